@@ -0,0 +1,6 @@
+fn main() {
+    // Emits `#[cfg(Py_GIL_DISABLED)]` (among others) for the target interpreter, so
+    // `src/future/mod.rs` can special-case free-threaded CPython.
+    // See: <https://pyo3.rs/v0.23.2/building-and-distribution/multiple-python-versions.html#using-pyo3-build-config>
+    pyo3_build_config::use_pyo3_cfgs();
+}
@@ -0,0 +1,215 @@
+//! A dedicated driver for the Rust side of async work, instead of every [crate::future::RustFuture]
+//! acquiring the GIL on its own, once per poll (see [crate::future::rust]'s own note on this). See
+//! [Driver].
+
+#![cfg(feature = "sync")]
+
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Condvar, Mutex,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use pyo3::{exceptions::PyRuntimeError, import_exception, prelude::*, types::PyCFunction};
+use tokio::sync::oneshot;
+
+use crate::{future::RustFuture, promise::RustPromise};
+
+import_exception!(asyncio, CancelledError);
+
+/// Wakes the single task it's attached to (by flipping its `ready` flag) and unparks the
+/// [Driver]'s thread, so a wakeup firing mid-throttle-interval doesn't have to wait out the rest
+/// of it.
+struct TaskWake {
+    ready: Arc<AtomicBool>,
+    park: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl Wake for TaskWake {
+    fn wake(self: Arc<Self>) {
+        self.ready.store(true, Ordering::Release);
+        notify(&self.park);
+    }
+}
+
+fn notify(park: &(Mutex<bool>, Condvar)) {
+    let (lock, condvar) = park;
+    *lock.lock().unwrap() = true;
+    condvar.notify_one();
+}
+
+struct DriverTask {
+    future: RustFuture,
+    result_tx: Option<oneshot::Sender<PyResult<PyObject>>>,
+    ready: Arc<AtomicBool>,
+    cancel_requested: Arc<AtomicBool>,
+}
+
+/// A single-threaded, current-thread executor for [RustFuture], spawned on a dedicated OS thread,
+/// so embedders get an explicit lifecycle object for the async runtime instead of relying on
+/// `App::run`'s implicit loop (or every `RustFuture` separately acquiring the GIL on every poll).
+///
+/// The driver thread waits (via a [Condvar]) until any task's waker fires or `throttle_interval`
+/// passes, then polls every ready task together under a single GIL acquisition instead of one
+/// GIL acquisition per task per poll. A wakeup is acted on at once: it is not held back to
+/// collect further wakeups within `throttle_interval`; only wakeups that land while a drain cycle
+/// is running are handled together in the next one. While any task is pending, the thread also
+/// takes the GIL once every `throttle_interval` even if no task is ready. No lock is ever held across that
+/// GIL acquisition: tasks are drained from the submission queue, and the park flag is read/reset,
+/// both before the `Python::with_gil` block begins.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct Driver {
+    submit_tx: Mutex<Option<mpsc::Sender<DriverTask>>>,
+    park: Arc<(Mutex<bool>, Condvar)>,
+    stop: Arc<AtomicBool>,
+    thread: Mutex<Option<JoinHandle<()>>>,
+}
+
+fn run(throttle_interval: Duration, submit_rx: mpsc::Receiver<DriverTask>, park: Arc<(Mutex<bool>, Condvar)>, stop: Arc<AtomicBool>) {
+    let mut tasks: Vec<DriverTask> = Vec::new();
+    while !stop.load(Ordering::Acquire) {
+        {
+            let (lock, condvar) = &*park;
+            let signaled = lock.lock().unwrap();
+            let mut signaled = if *signaled {
+                signaled
+            } else {
+                condvar.wait_timeout(signaled, throttle_interval).unwrap().0
+            };
+            *signaled = false;
+        }
+        if stop.load(Ordering::Acquire) {
+            break;
+        }
+        while let Ok(task) = submit_rx.try_recv() {
+            tasks.push(task);
+        }
+        if tasks.is_empty() {
+            continue;
+        }
+        Python::with_gil(|py| {
+            // Opportunistic flush point: we already hold the GIL for this whole batch.
+            crate::log::flush_queued_logs(py);
+            tasks.retain_mut(|task| {
+                if task.cancel_requested.load(Ordering::Acquire)
+                    && task.future.is_running()
+                    && !task.future.is_cancellation_required()
+                {
+                    let exc = CancelledError::new_err("Driver task cancelled");
+                    let exc = exc.value_bound(py).clone().unbind();
+                    let _ = task.future.cancel_with(py, Some(exc));
+                }
+                if !task.ready.swap(false, Ordering::AcqRel) {
+                    return true;
+                }
+                let waker = Waker::from(Arc::new(TaskWake {
+                    ready: task.ready.clone(),
+                    park: park.clone(),
+                }));
+                let mut cx = Context::from_waker(&waker);
+                match Pin::new(&mut task.future).poll(&mut cx) {
+                    Poll::Ready(result) => {
+                        if let Some(tx) = task.result_tx.take() {
+                            let _ = tx.send(result);
+                        }
+                        false
+                    }
+                    Poll::Pending => true,
+                }
+            });
+        });
+    }
+}
+
+#[pymethods]
+impl Driver {
+    /// `throttle_interval_ms` bounds how long the driver thread parks between drain cycles when
+    /// nothing has woken it (at least 1 ms); a waker firing ends the wait at once.
+    #[new]
+    #[pyo3(signature = (throttle_interval_ms = 1))]
+    fn new(throttle_interval_ms: u64) -> Self {
+        let (submit_tx, submit_rx) = mpsc::channel();
+        let park = Arc::new((Mutex::new(false), Condvar::new()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let throttle_interval = Duration::from_millis(throttle_interval_ms.max(1));
+
+        let thread = {
+            let park = park.clone();
+            let stop = stop.clone();
+            std::thread::Builder::new()
+                .name("pyfuture-driver".to_owned())
+                .spawn(move || run(throttle_interval, submit_rx, park, stop))
+                .expect("failed to spawn pyfuture `Driver` thread")
+        };
+
+        Self {
+            submit_tx: Mutex::new(Some(submit_tx)),
+            park,
+            stop,
+            thread: Mutex::new(Some(thread)),
+        }
+    }
+
+    /// Submit an awaitable to be driven on this `Driver`'s thread — same `runner`/`awaitable`
+    /// shape as [RustFuture::new] — and get back a [RustPromise] that synchronous Python code can
+    /// block on, poll non-blockingly, or cancel.
+    fn submit(&self, py: Python<'_>, runner: PyObject, awaitable: PyObject) -> PyResult<Py<RustPromise>> {
+        let submit_tx = self.submit_tx.lock().unwrap();
+        let submit_tx = submit_tx
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("Driver is stopped"))?;
+
+        let (result_tx, result_rx) = oneshot::channel();
+        let cancel_requested = Arc::new(AtomicBool::new(false));
+        let task = DriverTask {
+            future: RustFuture::new(runner, awaitable),
+            result_tx: Some(result_tx),
+            ready: Arc::new(AtomicBool::new(true)),
+            cancel_requested: cancel_requested.clone(),
+        };
+        submit_tx
+            .send(task)
+            .map_err(|_| PyRuntimeError::new_err("Driver is stopped"))?;
+        notify(&self.park);
+
+        let park = self.park.clone();
+        let cancel_handle = PyCFunction::new_closure(
+            py,
+            Some(c"_pyfuture_driver_cancel"),
+            None,
+            move |_args, _kwargs| {
+                cancel_requested.store(true, Ordering::Release);
+                notify(&park);
+                PyResult::Ok(())
+            },
+        )?
+        .into_any()
+        .unbind();
+
+        Py::new(py, RustPromise::new(result_rx, cancel_handle))
+    }
+
+    /// Stop the driver thread and wait (releasing the GIL) for it to exit. Already-submitted
+    /// tasks that haven't resolved yet are abandoned: their [RustPromise]s observe this the same
+    /// way any dropped Rust task does (see [crate::future::RustFuture]'s `Drop`).
+    ///
+    /// Idempotent: calling this more than once is a no-op past the first call.
+    fn stop(&self, py: Python<'_>) {
+        self.stop.store(true, Ordering::Release);
+        // Drop the sender half so `submit` starts failing fast instead of queuing tasks that
+        // will never run.
+        self.submit_tx.lock().unwrap().take();
+        notify(&self.park);
+        if let Some(handle) = self.thread.lock().unwrap().take() {
+            py.allow_threads(|| {
+                let _ = handle.join();
+            });
+        }
+    }
+}
@@ -8,6 +8,7 @@ use std::{
 };
 
 use pyo3::prelude::*;
+use pyo3_utils::ungil::UnsafeUngilExt;
 
 pub use py::PyFuture;
 pub use rust::{RustFuture, CancelOnDrop};
@@ -23,9 +24,91 @@ where
     type Output = F::Output;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let waker = cx.waker();
-        Python::with_gil(|gil| {
-            gil.allow_threads(|| pin!(&mut self.0).poll(&mut Context::from_waker(waker)))
-        })
+        // Under free-threaded CPython there's no GIL to release, so `allow_threads` would just
+        // be overhead: poll `self.0` directly instead.
+        #[cfg(Py_GIL_DISABLED)]
+        {
+            pin!(&mut self.0).poll(cx)
+        }
+        #[cfg(not(Py_GIL_DISABLED))]
+        {
+            let waker = cx.waker();
+            Python::with_gil(|gil| {
+                gil.allow_threads(|| pin!(&mut self.0).poll(&mut Context::from_waker(waker)))
+            })
+        }
+    }
+}
+
+/// Like [AllowThreads], but for futures that are `!`[Send] as long as they never touch the GIL,
+/// e.g. a future that only holds an [std::rc::Rc].
+///
+/// [AllowThreads] can't accept such a future: it requires `F: Send` so that pyo3's [Ungil] marker
+/// can vouch nothing GIL-bound crosses the `allow_threads` boundary. [Self::new] bypasses that
+/// check the same way [UnsafeUngilExt::allow_threads_unsend] does, so the constructor's caller
+/// takes over the guarantee instead.
+#[derive(Debug)]
+pub struct AllowThreadsUnsend<F>(F);
+
+impl<F> AllowThreadsUnsend<F> {
+    /// # Safety
+    ///
+    /// `future` (and anything it captures) must never acquire or hold the GIL while being
+    /// polled, i.e. it must not touch a `Py`/`Bound`/`PyObject`. The waker [Self::poll] forwards
+    /// into `future` must likewise not be woken from, or moved onto, a thread that's currently
+    /// holding the GIL while this future is mid-poll.
+    ///
+    /// # Example
+    ///
+    /**
+    ```rust
+    use std::rc::Rc;
+
+    use pyfuture::future::AllowThreadsUnsend;
+
+    async fn foo() {
+        let rc = Rc::new(42);
+        // `Rc` is `!Send`, but `future` never touches the GIL, so this is sound.
+        // `Box::pin`, since `AllowThreadsUnsend` is only a `Future` over an `Unpin` future.
+        let future = Box::pin(async move {
+            let _ = &rc;
+        });
+        // SAFETY: `future` never acquires or holds the GIL.
+        unsafe { AllowThreadsUnsend::new(future) }.await;
+    }
+    ```
+    */
+    pub unsafe fn new(future: F) -> Self {
+        Self(future)
+    }
+}
+
+impl<F> Future for AllowThreadsUnsend<F>
+where
+    F: Future + Unpin,
+    F::Output: Send,
+{
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Under free-threaded CPython there's no GIL to release, so `allow_threads_unsend` would
+        // just be overhead: poll `self.0` directly instead.
+        #[cfg(Py_GIL_DISABLED)]
+        {
+            pin!(&mut self.0).poll(cx)
+        }
+        #[cfg(not(Py_GIL_DISABLED))]
+        {
+            let waker = cx.waker();
+            Python::with_gil(|gil| {
+                // SAFETY: `Self::new`'s caller guaranteed `self.0` never touches the GIL while
+                // polled below with the GIL released.
+                unsafe {
+                    gil.allow_threads_unsend(&mut self.0, |future| {
+                        pin!(future).poll(&mut Context::from_waker(waker))
+                    })
+                }
+            })
+        }
     }
 }
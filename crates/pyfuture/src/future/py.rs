@@ -1,34 +1,117 @@
 use std::task::Waker;
 
-use pyo3::prelude::*;
+use pyo3::{import_exception, prelude::*, types::PyCFunction};
+
+import_exception!(asyncio, CancelledError);
+
+/// Pending / resolved (via [PyFuture::set_result]/[PyFuture::set_exception]) / structurally
+/// cancelled (via [PyFuture::cancel]) — kept as three distinct states so a genuine
+/// `CancelledError` delivered through `set_exception` (the Python awaitable really did raise it)
+/// isn't confused with the Rust side abandoning the future.
+#[derive(Debug)]
+enum FutureResult {
+    Pending,
+    Resolved(PyResult<PyObject>),
+    Cancelled,
+}
 
 #[pyclass(subclass)]
 pub struct PyFuture {
     #[pyo3(get)]
     awaitable: PyObject,
     waker: Waker,
-    result: Option<PyResult<PyObject>>,
+    result: FutureResult,
+    /// `true` if [Self::awaitable] looks like a `concurrent.futures.Future` (no `__await__`)
+    /// rather than an asyncio-style awaitable. Python-visible so the `runner` callable passed to
+    /// [crate::runner::Runner] can pick the right protocol itself instead of guessing: a
+    /// `concurrent.futures.Future` isn't driven by an event loop, so `runner` typically just
+    /// needs to return a cancel handle that calls `awaitable.cancel()`, rather than scheduling a
+    /// task. The handle must also accept the one exception argument that
+    /// [crate::future::RustFuture::cancel_with] may pass it, which `awaitable.cancel` itself does
+    /// not.
+    ///
+    /// [Self::wire_if_concurrent_future] is what actually drives a `concurrent.futures.Future` —
+    /// this flag only tells `runner` (and anyone else inspecting a `PyFuture`) which case it is.
+    #[pyo3(get)]
+    is_concurrent_future: bool,
 }
 
 impl PyFuture {
-    pub(crate) const fn new(awaitable: PyObject, waker: Waker) -> Self {
-        Self {
+    pub(crate) fn new(py: Python<'_>, awaitable: PyObject, waker: Waker) -> PyResult<Self> {
+        let is_concurrent_future = !awaitable.bind(py).hasattr("__await__")?;
+        Ok(Self {
             awaitable,
             waker,
-            result: None,
-        }
+            result: FutureResult::Pending,
+            is_concurrent_future,
+        })
     }
 
     fn wake(&self) {
         self.waker.wake_by_ref();
     }
 
+    /// For a `concurrent.futures.Future`-style [Self::awaitable] (see [Self::is_concurrent_future]),
+    /// register a done-callback on it (`awaitable.add_done_callback`) that reads `.result()`/
+    /// `.exception()` once the thread pool completes it and forwards to [Self::set_result]/
+    /// [Self::set_exception] — the same wake-up path an asyncio-driven `runner` would otherwise be
+    /// responsible for wiring up itself. A no-op for asyncio-style awaitables.
+    pub(crate) fn wire_if_concurrent_future(py: Python<'_>, py_future: &Bound<'_, Self>) -> PyResult<()> {
+        if !py_future.borrow().is_concurrent_future {
+            return Ok(());
+        }
+        let awaitable = py_future.borrow().awaitable.clone_ref(py);
+        let py_future = py_future.clone().unbind();
+        let on_done = PyCFunction::new_closure(
+            py,
+            Some(c"_pyfuture_on_concurrent_future_done"),
+            None,
+            move |args, _kwargs| {
+                let py = args.py();
+                let done = args.get_item(0)?;
+                let mut slf = py_future.borrow_mut(py);
+                let exception = done.call_method0("exception")?;
+                if !exception.is_none() {
+                    slf.set_exception(exception);
+                } else {
+                    let result = done.call_method0("result")?;
+                    slf.set_result(result.unbind());
+                }
+                PyResult::Ok(())
+            },
+        )?;
+        awaitable.bind(py).call_method1("add_done_callback", (on_done,))?;
+        Ok(())
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        matches!(self.result, FutureResult::Cancelled)
+    }
+
+    /// No-op once [Self::cancel] has run: the future is done, so a stale executor re-polling it
+    /// must not resurrect it by installing a new waker.
     pub(crate) fn waker_clone_from(&mut self, waker: &Waker) {
-        self.waker.clone_from(waker);
+        if !self.is_cancelled() {
+            self.waker.clone_from(waker);
+        }
     }
 
-    pub(crate) fn result_as_ref(&self) -> Option<&PyResult<PyObject>> {
-        self.result.as_ref()
+    /// `None` while pending; `Some` once resolved or cancelled. Clones out of `self.result`
+    /// (rather than borrowing it) since the Rust poller needs an owned [PyResult] to return from
+    /// [std::future::Future::poll].
+    pub(crate) fn result_as_ref(&self, py: Python<'_>) -> Option<PyResult<PyObject>> {
+        match &self.result {
+            FutureResult::Pending => None,
+            FutureResult::Resolved(result) => Some(
+                result
+                    .as_ref()
+                    .map(|ok| ok.clone_ref(py))
+                    .map_err(|err| err.clone_ref(py)),
+            ),
+            FutureResult::Cancelled => Some(Err(CancelledError::new_err(
+                "PyFuture was cancelled before the awaitable resolved",
+            ))),
+        }
     }
 
     // // we don't need yet, just leave it here for future use
@@ -41,12 +124,28 @@ impl PyFuture {
 #[pymethods]
 impl PyFuture {
     fn set_result(&mut self, result: PyObject) {
-        self.result = Some(Ok(result));
+        if self.is_cancelled() {
+            return;
+        }
+        self.result = FutureResult::Resolved(Ok(result));
         self.wake();
     }
 
     fn set_exception(&mut self, exception: Bound<'_, PyAny>) {
-        self.result = Some(Err(PyErr::from_value_bound(exception)));
+        if self.is_cancelled() {
+            return;
+        }
+        self.result = FutureResult::Resolved(Err(PyErr::from_value_bound(exception)));
         self.wake();
     }
+
+    /// Mark this future as structurally cancelled (e.g. the owning [crate::future::RustFuture]
+    /// was dropped) and wake the waker so the Rust poller observes the cancellation instead of
+    /// leaking a pending task. A no-op if the future has already resolved or been cancelled.
+    pub(crate) fn cancel(&mut self) {
+        if matches!(self.result, FutureResult::Pending) {
+            self.result = FutureResult::Cancelled;
+            self.wake();
+        }
+    }
 }
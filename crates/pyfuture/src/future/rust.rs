@@ -1,17 +1,17 @@
-// TODO, XXX: `eprintln` and `println` is not async-safe and atomic,
-// use `log` crate instead.
-// See: <https://pyo3.rs/v0.22.5/ecosystem/logging>
-
 use std::{
     future::Future,
     pin::{pin, Pin},
     task::{Context, Poll},
 };
 
-use pyo3::prelude::*;
+use pyo3::{import_exception, prelude::*};
 
 use crate::future::py::PyFuture;
 
+// Only used by `CancelOnDrop`, which throws it through `cancel_with` instead of cancelling bare;
+// see `crate::future::py` for how a cancelled `PyFuture` reports it.
+import_exception!(asyncio, CancelledError);
+
 #[derive(Debug)]
 struct InitRustFuture {
     pub(self) awaitable: PyObject,
@@ -78,13 +78,30 @@ impl RustFuture {
     // you have to use `&mut` to make sure only one thread can cancel the future at a time,
     // it's for thread-safe for python async runtime.
     pub fn cancel_bound(&mut self, py: Python<'_>) -> PyResult<PyObject> {
+        self.cancel_with(py, None)
+    }
+
+    /// Like [Self::cancel_bound], but when `exc` is supplied, invokes `cancel_handle` with it
+    /// (`cancel_handle.call1(py, (exc,))`) instead of calling it bare (`call0`) — the runner-side
+    /// counterpart of a `coro.throw(exc)`, so the awaitable's cancellation cleanup (`except`/
+    /// `finally` blocks) observes *why* it's being cancelled instead of just that it is.
+    ///
+    /// `exc` is forwarded as-is: it's the runner's job to interpret it as an exception
+    /// type/instance to throw, the same way it already interprets `cancel_handle`'s meaning.
+    pub fn cancel_with(&mut self, py: Python<'_>, exc: Option<PyObject>) -> PyResult<PyObject> {
         match &mut self.0 {
             RustFutureInner::Running(RunningRustFuture {
+                py_future,
                 cancel_handle,
                 cancellation_required,
-                ..
             }) => {
-                let result = cancel_handle.call0(py)?;
+                let result = match exc {
+                    Some(exc) => cancel_handle.call1(py, (exc,))?,
+                    None => cancel_handle.call0(py)?,
+                };
+                // Also mark the `PyFuture` itself cancelled and wake it, so anything still
+                // observing it (not just `cancel_handle`'s own task) sees the cancellation.
+                py_future.borrow_mut(py).cancel();
                 *cancellation_required = true;
                 Ok(result)
             }
@@ -103,8 +120,9 @@ impl RustFuture {
 impl Drop for RustFuture {
     fn drop(&mut self) {
         if self.is_running() && !self.is_cancellation_required() {
-            // TODO: use `log` crate, for recoding line number and file name
-            eprintln!("[Warning] {self:?}: RustFuture dropped when PyFuture maybe still running");
+            // Only buffers the record (see `crate::log`), so this stays safe to call from `Drop`:
+            // no GIL is acquired here, unlike the old `eprintln!` this replaces.
+            log::warn!("{self:?}: RustFuture dropped when PyFuture maybe still running");
         }
     }
 }
@@ -126,8 +144,14 @@ impl Future for RustFuture {
                 // But NOTE: DO NOT use any other lock in GIL, or it maybe cause deadlock;
                 // and release the GIL as soon as possible.
                 let running_rust_future = Python::with_gil(|py| {
-                    let future = PyFuture::new(awaitable, cx.waker().clone());
+                    let future = PyFuture::new(py, awaitable, cx.waker().clone())
+                        .expect("Failed to inspect awaitable's `__await__`");
                     let py_future = Bound::new(py, future).expect("Failed to create Py<PyFuture>");
+                    // For a `concurrent.futures.Future`-style awaitable, we drive the wake-up
+                    // ourselves instead of leaving it to `runner` (see `PyFuture::is_concurrent_future`);
+                    // a no-op for asyncio-style awaitables.
+                    PyFuture::wire_if_concurrent_future(py, &py_future)
+                        .expect("Failed to wire up concurrent.futures.Future callback");
 
                     let cancel_handle =
                         // we require the implementation of runner returns as soon as possible,
@@ -158,19 +182,17 @@ impl Future for RustFuture {
             RustFutureInner::Running(running_rust_future) => {
                 let RunningRustFuture { py_future, .. } = running_rust_future;
                 let result = Python::with_gil(|py| {
+                    // Opportunistic flush point: we already hold the GIL here, and this runs
+                    // every time the waker wakes this future, matching the queue's "flush when
+                    // the waker runs" contract (see `crate::log::init_logger`).
+                    crate::log::flush_queued_logs(py);
                     let mut py_future = py_future.borrow_mut(py);
-                    match py_future.result_as_ref() {
+                    match py_future.result_as_ref(py) {
                         None => {
                             py_future.waker_clone_from(cx.waker());
                             Poll::Pending
                         }
-                        Some(result) => {
-                            let result = result
-                                .as_ref()
-                                .map(|ok| ok.clone_ref(py))
-                                .map_err(|err| err.clone_ref(py));
-                            Poll::Ready(result)
-                        }
+                        Some(result) => Poll::Ready(result),
                     }
                 });
                 if result.is_ready() {
@@ -193,21 +215,26 @@ impl Drop for CancelOnDrop {
         let rs_future = &mut self.0;
         if rs_future.is_running() && !rs_future.is_cancellation_required() {
             Python::with_gil(|py| {
-                let result = rs_future.cancel_bound(py);
+                // Default to an explicit `CancelledError` throw (rather than `cancel_bound`'s
+                // bare `call0`) so the awaited task's cancellation cleanup (`except`/`finally`)
+                // observes a proper cancellation exception while unwinding, instead of just being
+                // torn down silently.
+                let exc = CancelledError::new_err("RustFuture cancelled on drop");
+                let exc = exc.value_bound(py).clone().unbind();
+                let result = rs_future.cancel_with(py, Some(exc));
                 if let Err(e) = result {
                     match e.traceback_bound(py).map(|t| t.format()) {
-                        // TODO: use `log` crate instead of `eprintln!`
                         Some(Ok(traceback)) => {
-                            eprintln!(
-                                "[Warning] Error while cancelling on drop: {}\n{}",
-                                e, traceback
-                            );
+                            log::warn!("Error while cancelling on drop: {}\n{}", e, traceback);
                         }
                         _ => {
-                            eprintln!("Warning] Error while cancelling on drop: {:?}", e);
+                            log::warn!("Error while cancelling on drop: {:?}", e);
                         }
                     }
                 }
+                // We already hold the GIL for `cancel_with` above, so this is a safe,
+                // opportunistic flush point even though we're inside `Drop`.
+                crate::log::flush_queued_logs(py);
             });
         }
     }
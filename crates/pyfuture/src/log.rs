@@ -0,0 +1,125 @@
+//! Bridges the [`log`](mod@log) crate facade to a Python callback, so
+//! [RustFuture](crate::future::RustFuture)'s and
+//! [CancelOnDrop](crate::future::CancelOnDrop)'s `Drop` diagnostics reach the embedder
+//! structurally instead of going to stderr via `eprintln!`.
+//!
+//! [init_logger] installs the bridge once per process; [flush_queued_logs] is the only thing
+//! allowed to actually call the Python callback, and must only be called from a call site that
+//! already holds the GIL — a `Drop` must never acquire the GIL just to flush, since that risks a
+//! deadlock. `log::warn!`/`log::error!` etc. only ever buffer a record, so they stay safe to call
+//! from `Drop`.
+
+use std::sync::OnceLock;
+
+use crossbeam_queue::SegQueue;
+use log::{Level, Log, Metadata, Record};
+use pyo3::prelude::*;
+
+/// A [Record] detached from its borrow, so it can outlive the `log::log!` call site that produced
+/// it and sit on [QUEUE] until [flush_queued_logs] delivers it to the Python callback.
+struct BufferedRecord {
+    level: Level,
+    file: Option<String>,
+    line: Option<u32>,
+    message: String,
+}
+
+static CALLBACK: OnceLock<Py<PyAny>> = OnceLock::new();
+static QUEUE: SegQueue<BufferedRecord> = SegQueue::new();
+
+struct PyLogBridge {
+    debug: bool,
+}
+
+impl Log for PyLogBridge {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level()
+            <= if self.debug {
+                Level::Debug
+            } else {
+                Level::Warn
+            }
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        // No GIL here: this must stay safe to call from `RustFuture`/`CancelOnDrop`'s `Drop`.
+        QUEUE.push(BufferedRecord {
+            level: record.level(),
+            file: record.file().map(str::to_owned),
+            line: record.line(),
+            message: record.args().to_string(),
+        });
+    }
+
+    // `log::logger().flush()` callers may also be holding a lock or be inside `Drop`, so this
+    // can't call the Python callback either. Use [flush_queued_logs] from a GIL-safe call site.
+    fn flush(&self) {}
+}
+
+fn level_str(level: Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warn => "warn",
+        Level::Info => "info",
+        Level::Debug => "debug",
+        Level::Trace => "trace",
+    }
+}
+
+/// Install `callback` as the process-wide destination for this crate's `log`-crate records
+/// (previously delivered via `eprintln!`): [RustFuture](crate::future::RustFuture) dropped while
+/// still running, and errors from [CancelOnDrop](crate::future::CancelOnDrop)'s cancel-on-drop.
+///
+/// `callback` is invoked as `callback(level, file, line, message)` where `level` is one of
+/// `"error"`/`"warn"`/`"info"`/`"debug"`/`"trace"`, `file`/`line` are the `Option`al source
+/// location `log::Record` carries, and `message` is the formatted log message. `debug` controls
+/// the max level: `Warn` and above if `false`, `Debug` and above if `true`.
+///
+/// Records are only ever buffered on a lock-free queue here; `callback` is not called until
+/// [flush_queued_logs] runs, so this is safe to have logging calls reach from inside `Drop`. An
+/// embedder should call [flush_queued_logs] opportunistically from a call site that already holds
+/// the GIL — e.g. `App::run_iteration`, or whenever a `RustFuture`'s waker runs.
+///
+/// Like [log::set_boxed_logger], this can only succeed once per process.
+#[pyfunction]
+pub fn init_logger(callback: Py<PyAny>, debug: bool) -> PyResult<()> {
+    CALLBACK
+        .set(callback)
+        .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("`init_logger` already called"))?;
+    log::set_max_level(if debug {
+        log::LevelFilter::Debug
+    } else {
+        log::LevelFilter::Warn
+    });
+    log::set_boxed_logger(Box::new(PyLogBridge { debug }))
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+}
+
+/// Drain every record buffered since the last flush and deliver each to the callback registered
+/// via [init_logger]. Takes `py` because it never acquires the GIL itself: the caller must
+/// already hold it.
+///
+/// A no-op if [init_logger] was never called — in that case no bridge is installed either, so
+/// nothing is ever queued.
+pub fn flush_queued_logs(py: Python<'_>) {
+    let Some(callback) = CALLBACK.get() else {
+        return;
+    };
+    while let Some(record) = QUEUE.pop() {
+        let result = callback.call1(
+            py,
+            (
+                level_str(record.level),
+                record.file,
+                record.line,
+                record.message,
+            ),
+        );
+        if let Err(e) = result {
+            e.write_unraisable(py, None);
+        }
+    }
+}
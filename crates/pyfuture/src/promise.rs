@@ -0,0 +1,214 @@
+//! The reverse of [crate::future]: there, Rust awaits a Python awaitable; here, synchronous
+//! Python (no `asyncio`/`trio` loop on the calling thread) blocks on a Rust task that's already
+//! in flight. See [RustPromise].
+
+#![cfg(feature = "sync")]
+
+use std::sync::{Condvar, Mutex};
+
+use pyo3::{exceptions::PyRuntimeError, prelude::*};
+use tokio::sync::oneshot;
+
+#[derive(Debug)]
+enum PromiseState {
+    Pending(oneshot::Receiver<PyResult<PyObject>>),
+    /// Some thread has taken `result_rx` out of [Self::Pending] and is blocked on it in
+    /// [RustPromise::pyawait]. Other callers wait on [RustPromise::condvar] rather than
+    /// observing this as a (fabricated) final result — see the "regression" note on
+    /// [RustPromise::pyawait] for why that distinction matters.
+    InFlight,
+    Done(PyResult<PyObject>),
+}
+
+fn dropped_task_error() -> PyErr {
+    PyRuntimeError::new_err("the Rust task was dropped before producing a result")
+}
+
+/// Wraps a Rust task already in flight — e.g. spawned via `Commands`/`Runner` to perform IPC,
+/// filesystem, or network work — and delivers its result to Python through a blocking
+/// [Self::pyawait] instead of requiring an event loop on the calling thread.
+///
+/// There's no `#[new]`: construct one from Rust with [Self::new], handing it the `oneshot`
+/// receiver half the spawned task resolves through, plus a `cancel_handle` Python callable that
+/// mirrors `RunningRustFuture`'s own field of the same name
+/// ([RustFuture::cancel_bound](crate::future::RustFuture::cancel_bound) uses the equivalent
+/// field for the opposite direction).
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct RustPromise {
+    // Kept outside `state` so `cancel` never has to contend with whichever thread currently
+    // owns `result_rx` (see [PromiseState::InFlight]).
+    cancel_handle: PyObject,
+    state: Mutex<PromiseState>,
+    /// Signalled whenever `state` transitions to [PromiseState::Done], so a [Self::pyawait]
+    /// call that found [PromiseState::InFlight] knows when to stop waiting.
+    condvar: Condvar,
+}
+
+impl RustPromise {
+    pub fn new(result_rx: oneshot::Receiver<PyResult<PyObject>>, cancel_handle: PyObject) -> Self {
+        Self {
+            cancel_handle,
+            state: Mutex::new(PromiseState::Pending(result_rx)),
+            condvar: Condvar::new(),
+        }
+    }
+
+    /// Block — without the GIL held — until `state` is [PromiseState::Done].
+    ///
+    /// Only one caller at a time ever holds `result_rx`: whoever finds [PromiseState::Pending]
+    /// takes it, marks the state [PromiseState::InFlight] so nobody else tries to take it too,
+    /// and releases the lock before blocking on `result_rx` itself. A concurrent caller that
+    /// instead finds [PromiseState::InFlight] parks on `condvar` until the first caller installs
+    /// [PromiseState::Done] and notifies it — it never reports a result on its own, fabricated or
+    /// otherwise.
+    fn wait_until_done(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            match &*state {
+                PromiseState::Done(_) => return,
+                PromiseState::InFlight => state = self.condvar.wait(state).unwrap(),
+                PromiseState::Pending(_) => break,
+            }
+        }
+        let PromiseState::Pending(result_rx) =
+            std::mem::replace(&mut *state, PromiseState::InFlight)
+        else {
+            unreachable!("just matched Pending above")
+        };
+        // Release the lock before the actual (potentially long) blocking wait, so `done()` and
+        // concurrent `pyawait()` callers aren't stuck behind this `std::sync::Mutex` too.
+        drop(state);
+
+        let result = result_rx
+            .blocking_recv()
+            .unwrap_or_else(|_| Err(dropped_task_error()));
+
+        *self.state.lock().unwrap() = PromiseState::Done(result);
+        self.condvar.notify_all();
+    }
+}
+
+#[pymethods]
+impl RustPromise {
+    /// Block the calling thread — after releasing the GIL — until the wrapped Rust task
+    /// completes, then return its result, or raise the error it failed with. Returns
+    /// immediately, without blocking, if the result is already available, whether from a prior
+    /// [Self::pyawait] or [Self::done] call.
+    fn pyawait(&self, py: Python<'_>) -> PyResult<PyObject> {
+        py.allow_threads(|| self.wait_until_done());
+
+        match &*self.state.lock().unwrap() {
+            PromiseState::Done(result) => result
+                .as_ref()
+                .map(|ok| ok.clone_ref(py))
+                .map_err(|err| err.clone_ref(py)),
+            PromiseState::Pending(_) | PromiseState::InFlight => {
+                unreachable!("wait_until_done only returns once state is Done")
+            }
+        }
+    }
+
+    /// Non-blocking: `true` once the task has completed, i.e. once [Self::pyawait] would return
+    /// immediately instead of blocking. Returns `false` while another thread is blocked inside
+    /// [Self::pyawait] ([PromiseState::InFlight]) even if that task has since finished, since
+    /// `result_rx` can only be consumed once and this call won't block waiting for it.
+    fn done(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        match &mut *state {
+            PromiseState::Done(_) => true,
+            PromiseState::InFlight => false,
+            PromiseState::Pending(result_rx) => match result_rx.try_recv() {
+                Ok(result) => {
+                    *state = PromiseState::Done(result);
+                    true
+                }
+                Err(oneshot::error::TryRecvError::Empty) => false,
+                Err(oneshot::error::TryRecvError::Closed) => {
+                    *state = PromiseState::Done(Err(dropped_task_error()));
+                    true
+                }
+            },
+        }
+    }
+
+    /// Ask the Rust task to stop, via the same `cancel_handle` call0 pattern
+    /// [RustFuture::cancel_bound](crate::future::RustFuture::cancel_bound) uses for the opposite
+    /// direction. A no-op once the result is already available.
+    fn cancel(&self, py: Python<'_>) -> PyResult<()> {
+        if let PromiseState::Done(_) = &*self.state.lock().unwrap() {
+            return Ok(());
+        }
+        self.cancel_handle.call0(py)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn pyawait_returns_the_sent_value() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let (tx, rx) = oneshot::channel();
+            let promise = RustPromise::new(rx, py.None());
+            tx.send(Ok(py.None())).unwrap();
+
+            let result = promise.pyawait(py).unwrap();
+            assert!(result.is_none(py));
+        });
+    }
+
+    /// Regression test for the race fixed alongside [PromiseState::InFlight]: a `pyawait()` call
+    /// that starts while another thread is already blocked on the real `result_rx` must wait for
+    /// the real result too, instead of observing a fabricated "dropped" error the moment the
+    /// first caller takes `result_rx` out of [PromiseState::Pending].
+    #[test]
+    fn concurrent_pyawait_callers_see_the_real_result_not_a_placeholder() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let (tx, rx) = oneshot::channel();
+            let promise = RustPromise::new(rx, py.None());
+
+            py.allow_threads(|| {
+                thread::scope(|scope| {
+                    let first = scope
+                        .spawn(|| Python::with_gil(|py| promise.pyawait(py).map(|v| v.is_none(py))));
+
+                    // Give `first` a head start so it's the one that takes `result_rx` and
+                    // transitions the state to `InFlight`.
+                    thread::sleep(Duration::from_millis(50));
+
+                    let second = scope
+                        .spawn(|| Python::with_gil(|py| promise.pyawait(py).map(|v| v.is_none(py))));
+
+                    // The task only "completes" now, well after `second` had a chance to observe
+                    // `InFlight` and start waiting on the condvar instead of fabricating a result.
+                    thread::sleep(Duration::from_millis(50));
+                    Python::with_gil(|py| tx.send(Ok(py.None())).unwrap());
+
+                    assert!(first.join().unwrap().unwrap());
+                    assert!(second.join().unwrap().unwrap());
+                })
+            });
+        });
+    }
+
+    #[test]
+    fn done_is_false_while_pending_and_true_after_send() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let (tx, rx) = oneshot::channel();
+            let promise = RustPromise::new(rx, py.None());
+            assert!(!promise.done());
+
+            tx.send(Ok(py.None())).unwrap();
+            assert!(promise.done());
+            assert!(promise.pyawait(py).unwrap().is_none(py));
+        });
+    }
+}
@@ -11,12 +11,17 @@ use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::mem::replace;
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use std::time::Duration;
 
 use parking_lot::{
-    MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    MappedMutexGuard, MappedRwLockReadGuard, MappedRwLockWriteGuard, Mutex, MutexGuard, RwLock,
+    RwLockReadGuard, RwLockUpgradableReadGuard, RwLockWriteGuard,
 };
 use pyo3::exceptions::PyRuntimeError;
-use pyo3::PyErr;
+use pyo3::{PyErr, Python};
+
+use crate::ungil::UnsafeUngilExt;
 
 const CONSUMED_ERROR_MSG: &str = "Already consumed";
 const LOCK_ERROR_MSG: &str = "Already mutably borrowed";
@@ -62,6 +67,178 @@ impl From<LockError> for PyErr {
 
 pub type LockResult<T> = Result<T, LockError>;
 
+const REENTRANT_ERROR_MSG: &str = "re-entrant lock on same thread";
+
+/// This error indicates that the current thread tried to lock a [PyWrapper] (via
+/// `lock_inner_*`, `try_lock_inner_*` or `blocking_lock_inner_*`) it already holds the write lock
+/// on, within the same call stack — i.e. reentrancy, not genuine
+/// cross-thread contention. Distinguishing the two matters because a reentrant `#[pymethod]`
+/// calling back into itself is a common and confusing bug under the GIL, and looks identical to
+/// real lock contention if both just surface [LockError].
+///
+/// Only ever produced with the `debug-lock` feature enabled, which is what pays for tracking the
+/// current writer's thread id alongside the lock.
+#[cfg(feature = "debug-lock")]
+#[derive(Debug)]
+pub struct ReentrantError;
+
+#[cfg(feature = "debug-lock")]
+impl Display for ReentrantError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{REENTRANT_ERROR_MSG}")
+    }
+}
+
+#[cfg(feature = "debug-lock")]
+impl Error for ReentrantError {}
+
+#[cfg(feature = "debug-lock")]
+impl From<ReentrantError> for PyErr {
+    fn from(_: ReentrantError) -> Self {
+        PyRuntimeError::new_err(REENTRANT_ERROR_MSG)
+    }
+}
+
+/// Replaces [LockError] as the failure type of [PyWrapper]'s primary lock-acquisition methods
+/// (`lock_inner_ref`/`lock_inner_mut` on [PyWrapperT1], `try_lock_inner_ref`/`try_lock_inner_mut`
+/// on [PyWrapperT2]) when the `debug-lock` feature is enabled, so a same-thread reentrant attempt
+/// is reported distinctly from genuine cross-thread contention instead of both collapsing into
+/// the same opaque [LockError].
+#[cfg(feature = "debug-lock")]
+#[derive(Debug)]
+pub enum DebugLockError {
+    Contended(LockError),
+    Reentrant(ReentrantError),
+}
+
+#[cfg(feature = "debug-lock")]
+impl Display for DebugLockError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Contended(e) => Display::fmt(e, f),
+            Self::Reentrant(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "debug-lock")]
+impl Error for DebugLockError {}
+
+#[cfg(feature = "debug-lock")]
+impl From<DebugLockError> for PyErr {
+    fn from(e: DebugLockError) -> Self {
+        match e {
+            DebugLockError::Contended(e) => e.into(),
+            DebugLockError::Reentrant(e) => e.into(),
+        }
+    }
+}
+
+#[cfg(feature = "debug-lock")]
+pub type DebugLockResult<T> = Result<T, DebugLockError>;
+
+/// Collapses the reentrant/contended distinction back into a plain [LockError], for call sites
+/// (e.g. [PyWrapperSemverExt]) whose signature is shared with the non-`debug-lock` build and so
+/// can't surface [DebugLockError] directly.
+#[cfg(feature = "debug-lock")]
+impl From<DebugLockError> for LockError {
+    fn from(_: DebugLockError) -> Self {
+        LockError
+    }
+}
+
+/// Sentinel `holder` value meaning "no thread currently holds the write lock".
+#[cfg(feature = "debug-lock")]
+const NO_HOLDER: u64 = 0;
+
+/// A cheap, process-local (not [std::thread::ThreadId]-based, since that can't be converted to
+/// an integer on stable) id for the current thread, assigned once per thread and cached in a
+/// `thread_local`. Never equal to [NO_HOLDER].
+#[cfg(feature = "debug-lock")]
+fn current_thread_id() -> u64 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    fn next_thread_id() -> u64 {
+        static COUNTER: AtomicU64 = AtomicU64::new(1);
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    }
+
+    std::thread_local! {
+        static THREAD_ID: u64 = next_thread_id();
+    }
+
+    THREAD_ID.with(|id| *id)
+}
+
+/// Wraps a write guard so that, with the `debug-lock` feature enabled, the wrapper's `holder`
+/// (see [PyWrapper]) is cleared back to [NO_HOLDER] when the write guard is dropped — otherwise a
+/// released lock would still look held to the reentrancy check above.
+#[cfg(feature = "debug-lock")]
+pub struct DebugWriteGuard<'a, T: ?Sized> {
+    guard: MappedRwLockWriteGuard<'a, T>,
+    holder: &'a std::sync::atomic::AtomicU64,
+}
+
+#[cfg(feature = "debug-lock")]
+impl<T: ?Sized> Deref for DebugWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+#[cfg(feature = "debug-lock")]
+impl<T: ?Sized> DerefMut for DebugWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+#[cfg(feature = "debug-lock")]
+impl<T: ?Sized> Drop for DebugWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        self.holder.store(NO_HOLDER, std::sync::atomic::Ordering::Release);
+    }
+}
+
+#[cfg(feature = "debug-lock")]
+impl<'a, T> MappableDerefMut<'a> for DebugWriteGuard<'a, T>
+where
+    T: ?Sized + 'a,
+{
+    fn map<U, F>(self, f: F) -> impl MappableDerefMut<'a, Target = U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        // `DebugWriteGuard` implements `Drop`, so `guard` can't be moved out of `self` directly;
+        // `self` is never dropped here, so `holder` stays set for the returned guard.
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so `guard` is read out exactly once.
+        let guard = unsafe { std::ptr::read(&this.guard) };
+        DebugWriteGuard {
+            guard: MappedRwLockWriteGuard::map(guard, f),
+            holder: this.holder,
+        }
+    }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDerefMut<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        let this = std::mem::ManuallyDrop::new(self);
+        let holder = this.holder;
+        // SAFETY: see `Self::map`.
+        let guard = unsafe { std::ptr::read(&this.guard) };
+        match MappedRwLockWriteGuard::try_map(guard, f) {
+            Ok(guard) => Ok(DebugWriteGuard { guard, holder }),
+            Err(guard) => Err(DebugWriteGuard { guard, holder }),
+        }
+    }
+}
+
 /// Can only obtain alias references
 pub type PyWrapperT0<T> = Result<T, Infallible>;
 /// Can obtain alias references and mutable references
@@ -78,6 +255,12 @@ pub type PyWrapperT0<T> = Result<T, Infallible>;
 pub type PyWrapperT1<T> = RwLock<Result<T, Infallible>>;
 /// Can obtain alias references, mutable references, and ownership
 pub type PyWrapperT2<T> = RwLock<Result<T, ConsumedError>>;
+/// Like [PyWrapperT2], but backed by a `Mutex` instead of an `RwLock`, so `T` only needs to be
+/// `Send`, not `Sync` — broadening the set of Rust types (e.g. ones holding a `Cell` or a
+/// single-producer handle) that can be exposed to Python at all. The tradeoff: there's no
+/// separate shared-read guard, so readers and writers alike go through the same single exclusive
+/// `MappedMutexGuard`.
+pub type PyWrapperT3<T> = Mutex<Result<T, ConsumedError>>;
 
 mod sealed {
     use super::*;
@@ -87,6 +270,7 @@ mod sealed {
     impl<T> PyWrapperT for PyWrapperT0<T> {}
     impl<T> PyWrapperT for PyWrapperT1<T> {}
     impl<T> PyWrapperT for PyWrapperT2<T> {}
+    impl<T> PyWrapperT for PyWrapperT3<T> {}
 
     pub trait SealedPyWrapper {}
 
@@ -97,12 +281,16 @@ mod sealed {
     impl<'a, T: ?Sized> SealedMappableDeref for &'a T {}
     impl<'a, T: ?Sized> SealedMappableDeref for RwLockReadGuard<'a, T> {}
     impl<'a, T: ?Sized> SealedMappableDeref for MappedRwLockReadGuard<'a, T> {}
+    impl<'a, T: ?Sized> SealedMappableDeref for MappedMutexGuard<'a, T> {}
 
     pub trait SealedMappableDerefMut {}
 
     impl<'a, T: ?Sized> SealedMappableDerefMut for &'a mut T {}
     impl<'a, T: ?Sized> SealedMappableDerefMut for RwLockWriteGuard<'a, T> {}
     impl<'a, T: ?Sized> SealedMappableDerefMut for MappedRwLockWriteGuard<'a, T> {}
+    impl<'a, T: ?Sized> SealedMappableDerefMut for MappedMutexGuard<'a, T> {}
+    #[cfg(feature = "debug-lock")]
+    impl<'a, T: ?Sized> SealedMappableDerefMut for super::DebugWriteGuard<'a, T> {}
 }
 
 trait RwLockExt {
@@ -111,6 +299,10 @@ trait RwLockExt {
     fn try_read_ext(&self) -> LockResult<RwLockReadGuard<'_, Self::T>>;
 
     fn try_write_ext(&self) -> LockResult<RwLockWriteGuard<'_, Self::T>>;
+
+    fn try_read_for_ext(&self, timeout: Duration) -> LockResult<RwLockReadGuard<'_, Self::T>>;
+
+    fn try_write_for_ext(&self, timeout: Duration) -> LockResult<RwLockWriteGuard<'_, Self::T>>;
 }
 
 impl<T> RwLockExt for RwLock<T> {
@@ -123,15 +315,50 @@ impl<T> RwLockExt for RwLock<T> {
     fn try_write_ext(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
         self.try_write().ok_or(LockError)
     }
+
+    fn try_read_for_ext(&self, timeout: Duration) -> LockResult<RwLockReadGuard<'_, T>> {
+        self.try_read_for(timeout).ok_or(LockError)
+    }
+
+    fn try_write_for_ext(&self, timeout: Duration) -> LockResult<RwLockWriteGuard<'_, T>> {
+        self.try_write_for(timeout).ok_or(LockError)
+    }
+}
+
+trait MutexExt {
+    type T;
+
+    fn try_lock_ext(&self) -> LockResult<MutexGuard<'_, Self::T>>;
+}
+
+impl<T> MutexExt for Mutex<T> {
+    type T = T;
+
+    fn try_lock_ext(&self) -> LockResult<MutexGuard<'_, T>> {
+        self.try_lock().ok_or(LockError)
+    }
 }
 
 /// This trait provides compatibility between `&T` and [parking_lot::RwLockReadGuard]
+///
+/// [Self::map]/[Self::try_map] are also the public projection API: a guard returned by e.g.
+/// `try_lock_inner_ref` can be narrowed to a sub-field of `T` (via `map`) or to an `Option`-typed
+/// sub-field (via `try_map`, which hands the original guard back on `None` instead of dropping
+/// it) before being handed to Python, without copying the field out or widening the lock's scope.
 pub trait MappableDeref<'a>: Deref + sealed::SealedMappableDeref {
     /// This method is similar to [parking_lot::RwLockReadGuard::map] and its sibling methods.
     fn map<U, F>(self, f: F) -> impl MappableDeref<'a, Target = U>
     where
         U: ?Sized + 'a,
         F: FnOnce(&Self::Target) -> &U;
+
+    /// Like [Self::map], but the projection may fail: on `None`, the original `Self` is handed
+    /// back unchanged instead of being dropped, so a held lock isn't released prematurely.
+    /// This method is similar to [parking_lot::RwLockReadGuard::try_map] and its sibling methods.
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDeref<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&Self::Target) -> Option<&U>;
 }
 
 impl<'a, T> MappableDeref<'a> for &'a T
@@ -145,6 +372,17 @@ where
     {
         f(self)
     }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDeref<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        match f(self) {
+            Some(u) => Ok(u),
+            None => Err(self),
+        }
+    }
 }
 
 impl<'a, T> MappableDeref<'a> for MappedRwLockReadGuard<'a, T>
@@ -158,6 +396,14 @@ where
     {
         MappedRwLockReadGuard::map(self, f)
     }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDeref<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        MappedRwLockReadGuard::try_map(self, f)
+    }
 }
 
 impl<'a, T> MappableDeref<'a> for RwLockReadGuard<'a, T>
@@ -171,6 +417,48 @@ where
     {
         RwLockReadGuard::map(self, f)
     }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDeref<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        RwLockReadGuard::try_map(self, f)
+    }
+}
+
+impl<'a, T> MappableDeref<'a> for MappedMutexGuard<'a, T>
+where
+    T: ?Sized + 'a,
+{
+    fn map<U, F>(self, f: F) -> impl MappableDeref<'a, Target = U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> &U,
+    {
+        // `MappedMutexGuard::map` only accepts `FnOnce(&mut T) -> &mut U`, since a `Mutex`'s
+        // access is always exclusive — there's no separate shared-read projection to call into.
+        // SAFETY: `f` only reads through the `&T` it's handed and returns a reference derived
+        // from it; re-deriving that same reference as `&mut U` just to satisfy `map`'s signature
+        // doesn't grant any actual mutation, since the `MappableDeref` impl returned here only
+        // ever exposes `U` through `Deref`, never `DerefMut`.
+        MappedMutexGuard::map(self, |inner| unsafe {
+            let r: &U = f(inner);
+            &mut *(r as *const U as *mut U)
+        })
+    }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDeref<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        // SAFETY: see `Self::map`.
+        MappedMutexGuard::try_map(self, |inner| {
+            let r = f(inner)?;
+            Some(unsafe { &mut *(r as *const U as *mut U) })
+        })
+    }
 }
 
 /// This trait provides compatibility between [&mut T] and [parking_lot::RwLockWriteGuard]
@@ -180,6 +468,14 @@ pub trait MappableDerefMut<'a>: DerefMut + sealed::SealedMappableDerefMut {
     where
         U: ?Sized + 'a,
         F: FnOnce(&mut Self::Target) -> &mut U;
+
+    /// Like [Self::map], but the projection may fail: on `None`, the original `Self` is handed
+    /// back unchanged instead of being dropped, so a held lock isn't released prematurely.
+    /// This method is similar to [parking_lot::RwLockWriteGuard::try_map] and its sibling methods.
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDerefMut<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&mut Self::Target) -> Option<&mut U>;
 }
 
 impl<'a, T> MappableDerefMut<'a> for &'a mut T
@@ -193,6 +489,22 @@ where
     {
         f(self)
     }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDerefMut<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        // `f(self)` would reborrow `self` for a shorter lifetime than `'a`, so recovering the
+        // original `&'a mut T` on `None` needs a raw pointer round-trip (the same trick
+        // `parking_lot`'s own `try_map` uses internally).
+        let self_ptr: *mut T = self;
+        match f(self) {
+            Some(u) => Ok(u),
+            // SAFETY: `f` returned `None`, so the `&mut T` it was given was not retained.
+            None => Err(unsafe { &mut *self_ptr }),
+        }
+    }
 }
 
 impl<'a, T> MappableDerefMut<'a> for MappedRwLockWriteGuard<'a, T>
@@ -206,6 +518,14 @@ where
     {
         MappedRwLockWriteGuard::map(self, f)
     }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDerefMut<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        MappedRwLockWriteGuard::try_map(self, f)
+    }
 }
 
 impl<'a, T> MappableDerefMut<'a> for RwLockWriteGuard<'a, T>
@@ -219,6 +539,67 @@ where
     {
         RwLockWriteGuard::map(self, f)
     }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDerefMut<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        RwLockWriteGuard::try_map(self, f)
+    }
+}
+
+impl<'a, T> MappableDerefMut<'a> for MappedMutexGuard<'a, T>
+where
+    T: ?Sized + 'a,
+{
+    fn map<U, F>(self, f: F) -> impl MappableDerefMut<'a, Target = U>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        MappedMutexGuard::map(self, f)
+    }
+
+    fn try_map<U, F>(self, f: F) -> Result<impl MappableDerefMut<'a, Target = U>, Self>
+    where
+        U: ?Sized + 'a,
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        MappedMutexGuard::try_map(self, f)
+    }
+}
+
+/// An upgradable-read guard obtained via `try_lock_inner_upgradable` (on [PyWrapperT1]/
+/// [PyWrapperT2]): permits further shared reads while held, and can be turned into a write
+/// guard via [Self::upgrade] without a gap where another writer could slip in between a dropped
+/// read guard and a freshly-acquired write guard. This is what lets a single Python-facing
+/// method read-then-conditionally-write without ever releasing the lock in between.
+///
+/// Deliberately not threaded through [MappableDeref]/[MappableDerefMut]: projecting with `.map()`
+/// would discard the concrete [RwLockUpgradableReadGuard] that [Self::upgrade] needs, so this
+/// guard exposes a plain [Deref] instead.
+pub struct UpgradableGuard<'a, T, E>(RwLockUpgradableReadGuard<'a, Result<T, E>>);
+
+impl<T, E> Deref for UpgradableGuard<'_, T, E> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // invariant: only constructed from a guard already known to hold `Ok`, see
+        // `try_lock_inner_upgradable` on `PyWrapper<PyWrapperT1>`/`PyWrapper<PyWrapperT2>`.
+        self.0.as_ref().unwrap()
+    }
+}
+
+impl<'a, T, E> UpgradableGuard<'a, T, E> {
+    /// Atomically upgrade this read guard to a write guard: no other writer can observe the
+    /// value in between, which is what makes check-then-mutate safe.
+    pub fn upgrade(self) -> MappedRwLockWriteGuard<'a, T> {
+        let guard = RwLockUpgradableReadGuard::upgrade(self.0);
+        // invariant upheld: nothing could have replaced the `Ok` with an `Err` while the
+        // upgradable read was held, since doing so requires a write lock.
+        RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap())
+    }
 }
 
 /// You can wrap the desired internal value in this structure to implement a pyclass that
@@ -267,12 +648,23 @@ where
     T: sealed::PyWrapperT,
 {
     inner: T,
+    /// Tracks which thread currently holds the write lock (see [NO_HOLDER]/[current_thread_id]),
+    /// so a reentrant `lock_inner_*`/`try_lock_inner_*`/`blocking_lock_inner_*` call from that
+    /// same thread can be reported as a
+    /// [ReentrantError] instead of an opaque [LockError]. Only present with the `debug-lock`
+    /// feature, since tracking this isn't free; [PyWrapperT0] never sets or checks it.
+    #[cfg(feature = "debug-lock")]
+    holder: std::sync::atomic::AtomicU64,
 }
 
 impl<T> PyWrapper<PyWrapperT0<T>> {
     #[inline]
     pub fn new0(inner: T) -> Self {
-        Self { inner: Ok(inner) }
+        Self {
+            inner: Ok(inner),
+            #[cfg(feature = "debug-lock")]
+            holder: std::sync::atomic::AtomicU64::new(NO_HOLDER),
+        }
     }
 
     #[inline]
@@ -299,9 +691,12 @@ impl<T> PyWrapper<PyWrapperT1<T>> {
     pub fn new1(inner: T) -> Self {
         Self {
             inner: RwLock::new(Ok(inner)),
+            #[cfg(feature = "debug-lock")]
+            holder: std::sync::atomic::AtomicU64::new(NO_HOLDER),
         }
     }
 
+    #[cfg(not(feature = "debug-lock"))]
     pub fn lock_inner_ref(&self) -> LockResult<MappedRwLockReadGuard<'_, T>> {
         self.inner
             .try_read_ext()
@@ -309,6 +704,7 @@ impl<T> PyWrapper<PyWrapperT1<T>> {
             .map(|guard| RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap()))
     }
 
+    #[cfg(not(feature = "debug-lock"))]
     pub fn lock_inner_mut(&self) -> LockResult<MappedRwLockWriteGuard<'_, T>> {
         self.inner
             .try_write_ext()
@@ -316,6 +712,81 @@ impl<T> PyWrapper<PyWrapperT1<T>> {
             .map(|guard| RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap()))
     }
 
+    /// Like the non-`debug-lock` [Self::lock_inner_ref], but on contention also checks whether
+    /// the calling thread is the one currently holding the write lock — if so, this is a
+    /// reentrant call into a `#[pymethod]` that transitively tries to lock what it already holds,
+    /// reported as [ReentrantError] rather than indistinguishable cross-thread [LockError].
+    #[cfg(feature = "debug-lock")]
+    pub fn lock_inner_ref(&self) -> DebugLockResult<MappedRwLockReadGuard<'_, T>> {
+        use std::sync::atomic::Ordering;
+
+        match self.inner.try_read_ext() {
+            // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
+            Ok(guard) => Ok(RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap())),
+            Err(e) => {
+                if self.holder.load(Ordering::Acquire) == current_thread_id() {
+                    Err(DebugLockError::Reentrant(ReentrantError))
+                } else {
+                    Err(DebugLockError::Contended(e))
+                }
+            }
+        }
+    }
+
+    /// Like the non-`debug-lock` [Self::lock_inner_mut], but see [Self::lock_inner_ref] for the
+    /// reentrancy check, and [DebugWriteGuard] for why the returned guard clears `holder` on
+    /// drop.
+    #[cfg(feature = "debug-lock")]
+    pub fn lock_inner_mut(&self) -> DebugLockResult<DebugWriteGuard<'_, T>> {
+        use std::sync::atomic::Ordering;
+
+        match self.inner.try_write_ext() {
+            Ok(guard) => {
+                self.holder.store(current_thread_id(), Ordering::Release);
+                Ok(DebugWriteGuard {
+                    // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
+                    guard: RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap()),
+                    holder: &self.holder,
+                })
+            }
+            Err(e) => {
+                if self.holder.load(Ordering::Acquire) == current_thread_id() {
+                    Err(DebugLockError::Reentrant(ReentrantError))
+                } else {
+                    Err(DebugLockError::Contended(e))
+                }
+            }
+        }
+    }
+
+    /// Like [Self::lock_inner_ref], but waits up to `timeout` for the lock instead of failing
+    /// instantly, so a caller can release the GIL and wait a bounded time for another thread.
+    pub fn try_lock_inner_ref_for(&self, timeout: Duration) -> LockResult<MappedRwLockReadGuard<'_, T>> {
+        self.inner
+            .try_read_for_ext(timeout)
+            // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
+            .map(|guard| RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap()))
+    }
+
+    /// Like [Self::lock_inner_mut], but waits up to `timeout` for the lock instead of failing
+    /// instantly, so a caller can release the GIL and wait a bounded time for another thread.
+    pub fn try_lock_inner_mut_for(&self, timeout: Duration) -> LockResult<MappedRwLockWriteGuard<'_, T>> {
+        self.inner
+            .try_write_for_ext(timeout)
+            // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
+            .map(|guard| RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap()))
+    }
+
+    /// Like [Self::lock_inner_ref], but the returned guard can later be atomically upgraded to
+    /// a write guard via [UpgradableGuard::upgrade], for check-then-mutate without dropping and
+    /// re-acquiring the lock in between.
+    pub fn try_lock_inner_upgradable(&self) -> LockResult<UpgradableGuard<'_, T, Infallible>> {
+        self.inner
+            .try_upgradable_read()
+            .ok_or(LockError)
+            .map(UpgradableGuard)
+    }
+
     pub fn into_inner(self) -> T {
         // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
         self.inner.into_inner().unwrap()
@@ -338,14 +809,91 @@ impl<T> PyWrapper<PyWrapperT1<T>> {
     }
 }
 
+// `T: Send` so the returned guard is itself `Ungil` and can cross the `allow_threads` boundary
+// opened by [UnsafeUngilExt::allow_threads_unsend] below; this is also already required in
+// practice of any `T` wrapped by a non-`unsendable` (i.e. GIL-independent, cross-thread) pyclass,
+// which is the only case where blocking on another thread's lock is actually meaningful.
+impl<T: Send> PyWrapper<PyWrapperT1<T>> {
+    /// Like [Self::lock_inner_ref], but blocks waiting for the lock instead of failing instantly
+    /// on contention, releasing the GIL while waiting (via
+    /// [UnsafeUngilExt::allow_threads_unsend]) so a thread parked here doesn't pin the GIL
+    /// against another thread that holds the lock but needs the GIL to finish and release it —
+    /// the classic lock/GIL deadlock.
+    #[cfg(not(feature = "debug-lock"))]
+    pub fn blocking_lock_inner_ref(&self, py: Python<'_>) -> MappedRwLockReadGuard<'_, T> {
+        // SAFETY: `&self.inner` does not hold the GIL.
+        let guard = unsafe { py.allow_threads_unsend(&self.inner, |inner| inner.read()) };
+        // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
+        RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap())
+    }
+
+    /// Like [Self::lock_inner_mut], but blocks waiting for the lock instead of failing instantly
+    /// on contention; see [Self::blocking_lock_inner_ref] for why releasing the GIL while
+    /// waiting matters.
+    #[cfg(not(feature = "debug-lock"))]
+    pub fn blocking_lock_inner_mut(&self, py: Python<'_>) -> MappedRwLockWriteGuard<'_, T> {
+        // SAFETY: `&self.inner` does not hold the GIL.
+        let guard = unsafe { py.allow_threads_unsend(&self.inner, |inner| inner.write()) };
+        // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
+        RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap())
+    }
+
+    /// Like the non-`debug-lock` [Self::blocking_lock_inner_ref], but checks [Self::lock_inner_ref]'s
+    /// reentrancy condition *before* blocking: `parking_lot::RwLock::read` is not reentrant, so
+    /// blocking unconditionally on a same-thread reentrant call (e.g. a `#[pymethod]` that already
+    /// holds the write lock transitively calling this) would deadlock the thread instead of
+    /// surfacing [ReentrantError].
+    #[cfg(feature = "debug-lock")]
+    pub fn blocking_lock_inner_ref(
+        &self,
+        py: Python<'_>,
+    ) -> DebugLockResult<MappedRwLockReadGuard<'_, T>> {
+        use std::sync::atomic::Ordering;
+
+        if self.holder.load(Ordering::Acquire) == current_thread_id() {
+            return Err(DebugLockError::Reentrant(ReentrantError));
+        }
+        // SAFETY: `&self.inner` does not hold the GIL.
+        let guard = unsafe { py.allow_threads_unsend(&self.inner, |inner| inner.read()) };
+        // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
+        Ok(RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap()))
+    }
+
+    /// Like the non-`debug-lock` [Self::blocking_lock_inner_mut], but see
+    /// [Self::blocking_lock_inner_ref] for the reentrancy check this adds, and
+    /// [DebugWriteGuard] for why the returned guard clears `holder` on drop.
+    #[cfg(feature = "debug-lock")]
+    pub fn blocking_lock_inner_mut(
+        &self,
+        py: Python<'_>,
+    ) -> DebugLockResult<DebugWriteGuard<'_, T>> {
+        use std::sync::atomic::Ordering;
+
+        if self.holder.load(Ordering::Acquire) == current_thread_id() {
+            return Err(DebugLockError::Reentrant(ReentrantError));
+        }
+        // SAFETY: `&self.inner` does not hold the GIL.
+        let guard = unsafe { py.allow_threads_unsend(&self.inner, |inner| inner.write()) };
+        self.holder.store(current_thread_id(), Ordering::Release);
+        Ok(DebugWriteGuard {
+            // TODO, FIXME: use [Result::into_ok] instead (unstable for now)
+            guard: RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap()),
+            holder: &self.holder,
+        })
+    }
+}
+
 impl<T> PyWrapper<PyWrapperT2<T>> {
     #[inline]
     pub fn new2(inner: T) -> Self {
         Self {
             inner: RwLock::new(Ok(inner)),
+            #[cfg(feature = "debug-lock")]
+            holder: std::sync::atomic::AtomicU64::new(NO_HOLDER),
         }
     }
 
+    #[cfg(not(feature = "debug-lock"))]
     pub fn try_lock_inner_ref(&self) -> LockResult<ConsumedResult<MappedRwLockReadGuard<'_, T>>> {
         self.try_read().map(|guard| {
             if guard.is_err() {
@@ -357,6 +905,7 @@ impl<T> PyWrapper<PyWrapperT2<T>> {
         })
     }
 
+    #[cfg(not(feature = "debug-lock"))]
     pub fn try_lock_inner_mut(&self) -> LockResult<ConsumedResult<MappedRwLockWriteGuard<'_, T>>> {
         self.try_write().map(|guard| {
             if guard.is_err() {
@@ -370,6 +919,60 @@ impl<T> PyWrapper<PyWrapperT2<T>> {
         })
     }
 
+    /// Like the non-`debug-lock` [Self::try_lock_inner_ref], but see
+    /// [PyWrapper::<PyWrapperT1<T>>::lock_inner_ref] for the reentrancy check this adds.
+    #[cfg(feature = "debug-lock")]
+    pub fn try_lock_inner_ref(
+        &self,
+    ) -> DebugLockResult<ConsumedResult<MappedRwLockReadGuard<'_, T>>> {
+        use std::sync::atomic::Ordering;
+
+        match self.try_read() {
+            Ok(guard) => Ok(if guard.is_err() {
+                Err(ConsumedError)
+            } else {
+                // PEFR: it's ok to use [unwrap_unchecked], but i dont like unsafe block
+                Ok(RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap()))
+            }),
+            Err(e) => {
+                if self.holder.load(Ordering::Acquire) == current_thread_id() {
+                    Err(DebugLockError::Reentrant(ReentrantError))
+                } else {
+                    Err(DebugLockError::Contended(e))
+                }
+            }
+        }
+    }
+
+    /// Like the non-`debug-lock` [Self::try_lock_inner_mut], but see
+    /// [PyWrapper::<PyWrapperT1<T>>::lock_inner_mut] for the reentrancy check and why the
+    /// returned guard is a [DebugWriteGuard].
+    #[cfg(feature = "debug-lock")]
+    pub fn try_lock_inner_mut(
+        &self,
+    ) -> DebugLockResult<ConsumedResult<DebugWriteGuard<'_, T>>> {
+        use std::sync::atomic::Ordering;
+
+        match self.try_write() {
+            Ok(guard) => Ok(if guard.is_err() {
+                Err(ConsumedError)
+            } else {
+                self.holder.store(current_thread_id(), Ordering::Release);
+                Ok(DebugWriteGuard {
+                    guard: RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap()),
+                    holder: &self.holder,
+                })
+            }),
+            Err(e) => {
+                if self.holder.load(Ordering::Acquire) == current_thread_id() {
+                    Err(DebugLockError::Reentrant(ReentrantError))
+                } else {
+                    Err(DebugLockError::Contended(e))
+                }
+            }
+        }
+    }
+
     pub fn try_take_inner(&self) -> LockResult<ConsumedResult<T>> {
         self.try_replace_inner(Err(ConsumedError))
     }
@@ -382,6 +985,60 @@ impl<T> PyWrapper<PyWrapperT2<T>> {
         })
     }
 
+    /// Like [Self::try_lock_inner_ref], but waits up to `timeout` for the lock instead of
+    /// failing instantly, so a caller can release the GIL and wait a bounded time for another
+    /// thread.
+    pub fn try_lock_inner_ref_for(
+        &self,
+        timeout: Duration,
+    ) -> LockResult<ConsumedResult<MappedRwLockReadGuard<'_, T>>> {
+        self.try_read_for(timeout).map(|guard| {
+            if guard.is_err() {
+                Err(ConsumedError)
+            } else {
+                // PEFR: it's ok to use [unwrap_unchecked], but i dont like unsafe block
+                Ok(RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap()))
+            }
+        })
+    }
+
+    /// Like [Self::try_lock_inner_mut], but waits up to `timeout` for the lock instead of
+    /// failing instantly, so a caller can release the GIL and wait a bounded time for another
+    /// thread.
+    pub fn try_lock_inner_mut_for(
+        &self,
+        timeout: Duration,
+    ) -> LockResult<ConsumedResult<MappedRwLockWriteGuard<'_, T>>> {
+        self.try_write_for(timeout).map(|guard| {
+            if guard.is_err() {
+                Err(ConsumedError)
+            } else {
+                // PEFR: it's ok to use [unwrap_unchecked], but i dont like unsafe block
+                Ok(RwLockWriteGuard::map(guard, |inner| {
+                    inner.as_mut().unwrap()
+                }))
+            }
+        })
+    }
+
+    /// Like [Self::try_lock_inner_ref], but the returned guard can later be atomically upgraded
+    /// to a write guard via [UpgradableGuard::upgrade], for check-then-mutate without dropping
+    /// and re-acquiring the lock in between.
+    pub fn try_lock_inner_upgradable(
+        &self,
+    ) -> LockResult<ConsumedResult<UpgradableGuard<'_, T, ConsumedError>>> {
+        self.inner
+            .try_upgradable_read()
+            .ok_or(LockError)
+            .map(|guard| {
+                if guard.is_err() {
+                    Err(ConsumedError)
+                } else {
+                    Ok(UpgradableGuard(guard))
+                }
+            })
+    }
+
     /// similar to [parking_lot::RwLock::try_read]
     pub fn try_read(&self) -> LockResult<RwLockReadGuard<'_, ConsumedResult<T>>> {
         self.inner.try_read_ext()
@@ -392,6 +1049,19 @@ impl<T> PyWrapper<PyWrapperT2<T>> {
         self.inner.try_write_ext()
     }
 
+    /// similar to [parking_lot::RwLock::try_read_for]
+    pub fn try_read_for(&self, timeout: Duration) -> LockResult<RwLockReadGuard<'_, ConsumedResult<T>>> {
+        self.inner.try_read_for_ext(timeout)
+    }
+
+    /// similar to [parking_lot::RwLock::try_write_for]
+    pub fn try_write_for(
+        &self,
+        timeout: Duration,
+    ) -> LockResult<RwLockWriteGuard<'_, ConsumedResult<T>>> {
+        self.inner.try_write_for_ext(timeout)
+    }
+
     pub fn try_into_inner(self) -> ConsumedResult<T> {
         self.inner.into_inner()
     }
@@ -399,6 +1069,7 @@ impl<T> PyWrapper<PyWrapperT2<T>> {
     /// # Panics
     ///
     /// Panics if the internal value has already been consumed, i.e., its ownership has been moved out.
+    #[cfg(not(feature = "debug-lock"))]
     #[deprecated(note = "use `try_lock_inner_ref` instead")]
     pub fn lock_inner_ref(&self) -> LockResult<MappedRwLockReadGuard<'_, T>> {
         self.try_lock_inner_ref()
@@ -408,12 +1079,33 @@ impl<T> PyWrapper<PyWrapperT2<T>> {
     /// # Panics
     ///
     /// Panics if the internal value has already been consumed, i.e., its ownership has been moved out.
+    #[cfg(not(feature = "debug-lock"))]
     #[deprecated(note = "use `try_lock_inner_mut` instead")]
     pub fn lock_inner_mut(&self) -> LockResult<MappedRwLockWriteGuard<'_, T>> {
         self.try_lock_inner_mut()
             .map(|result| result.expect(CONSUMED_ERROR_MSG))
     }
 
+    /// # Panics
+    ///
+    /// Panics if the internal value has already been consumed or this is a reentrant call.
+    #[cfg(feature = "debug-lock")]
+    #[deprecated(note = "use `try_lock_inner_ref` instead")]
+    pub fn lock_inner_ref(&self) -> DebugLockResult<MappedRwLockReadGuard<'_, T>> {
+        self.try_lock_inner_ref()
+            .map(|result| result.expect(CONSUMED_ERROR_MSG))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal value has already been consumed or this is a reentrant call.
+    #[cfg(feature = "debug-lock")]
+    #[deprecated(note = "use `try_lock_inner_mut` instead")]
+    pub fn lock_inner_mut(&self) -> DebugLockResult<DebugWriteGuard<'_, T>> {
+        self.try_lock_inner_mut()
+            .map(|result| result.expect(CONSUMED_ERROR_MSG))
+    }
+
     /// # Panics
     ///
     /// Panics if the internal value has already been mutably borrowed or consumed.
@@ -443,6 +1135,268 @@ impl<T> PyWrapper<PyWrapperT2<T>> {
     }
 }
 
+// See the `T: Send` note on the analogous `impl<T: Send> PyWrapper<PyWrapperT1<T>>` block above.
+impl<T: Send> PyWrapper<PyWrapperT2<T>> {
+    /// Like [Self::try_lock_inner_ref], but blocks waiting for the lock instead of failing
+    /// instantly on contention, releasing the GIL while waiting; see
+    /// [PyWrapper::<PyWrapperT1<T>>::blocking_lock_inner_ref] for why releasing the GIL while
+    /// waiting matters.
+    #[cfg(not(feature = "debug-lock"))]
+    pub fn blocking_lock_inner_ref(
+        &self,
+        py: Python<'_>,
+    ) -> ConsumedResult<MappedRwLockReadGuard<'_, T>> {
+        // SAFETY: `&self.inner` does not hold the GIL.
+        let guard = unsafe { py.allow_threads_unsend(&self.inner, |inner| inner.read()) };
+        if guard.is_err() {
+            Err(ConsumedError)
+        } else {
+            Ok(RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap()))
+        }
+    }
+
+    /// Like [Self::try_lock_inner_mut], but blocks waiting for the lock instead of failing
+    /// instantly on contention; see [Self::blocking_lock_inner_ref] for why releasing the GIL
+    /// while waiting matters.
+    #[cfg(not(feature = "debug-lock"))]
+    pub fn blocking_lock_inner_mut(
+        &self,
+        py: Python<'_>,
+    ) -> ConsumedResult<MappedRwLockWriteGuard<'_, T>> {
+        // SAFETY: `&self.inner` does not hold the GIL.
+        let guard = unsafe { py.allow_threads_unsend(&self.inner, |inner| inner.write()) };
+        if guard.is_err() {
+            Err(ConsumedError)
+        } else {
+            Ok(RwLockWriteGuard::map(guard, |inner| {
+                inner.as_mut().unwrap()
+            }))
+        }
+    }
+
+    /// Like the non-`debug-lock` [Self::blocking_lock_inner_ref], but see
+    /// [PyWrapper::<PyWrapperT1<T>>::blocking_lock_inner_ref] for the reentrancy check this adds,
+    /// checked *before* blocking so a same-thread reentrant call can't deadlock.
+    #[cfg(feature = "debug-lock")]
+    pub fn blocking_lock_inner_ref(
+        &self,
+        py: Python<'_>,
+    ) -> DebugLockResult<ConsumedResult<MappedRwLockReadGuard<'_, T>>> {
+        use std::sync::atomic::Ordering;
+
+        if self.holder.load(Ordering::Acquire) == current_thread_id() {
+            return Err(DebugLockError::Reentrant(ReentrantError));
+        }
+        // SAFETY: `&self.inner` does not hold the GIL.
+        let guard = unsafe { py.allow_threads_unsend(&self.inner, |inner| inner.read()) };
+        Ok(if guard.is_err() {
+            Err(ConsumedError)
+        } else {
+            Ok(RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap()))
+        })
+    }
+
+    /// Like the non-`debug-lock` [Self::blocking_lock_inner_mut], but see
+    /// [PyWrapper::<PyWrapperT1<T>>::blocking_lock_inner_ref] for the reentrancy check this adds,
+    /// and [DebugWriteGuard] for why the returned guard clears `holder` on drop.
+    #[cfg(feature = "debug-lock")]
+    pub fn blocking_lock_inner_mut(
+        &self,
+        py: Python<'_>,
+    ) -> DebugLockResult<ConsumedResult<DebugWriteGuard<'_, T>>> {
+        use std::sync::atomic::Ordering;
+
+        if self.holder.load(Ordering::Acquire) == current_thread_id() {
+            return Err(DebugLockError::Reentrant(ReentrantError));
+        }
+        // SAFETY: `&self.inner` does not hold the GIL.
+        let guard = unsafe { py.allow_threads_unsend(&self.inner, |inner| inner.write()) };
+        Ok(if guard.is_err() {
+            Err(ConsumedError)
+        } else {
+            self.holder.store(current_thread_id(), Ordering::Release);
+            Ok(DebugWriteGuard {
+                guard: RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap()),
+                holder: &self.holder,
+            })
+        })
+    }
+}
+
+/// An owned read guard returned by e.g. [PyWrapper::<PyWrapperT1<T>>::try_lock_inner_ref_owned],
+/// so a live lock guard can be stored inside a `#[pyclass]` (which, unlike [PyWrapper] itself,
+/// cannot carry a borrowed lifetime) instead of only ever being handed out borrowed from `&self`
+/// — e.g. a context-manager object that holds the read lock across several `__enter__`/method
+/// calls.
+///
+/// # Safety invariant
+///
+/// `guard`'s lifetime is unsafely extended to `'static`; this is sound only because `_owner`
+/// keeps the backing `RwLock` allocation alive for at least as long as `guard` does, and because
+/// field declaration order (`guard` before `_owner`) makes Rust drop `guard` — releasing the lock
+/// — before `_owner` is dropped.
+pub struct OwnedPyWrapperReadGuard<T: 'static> {
+    guard: MappedRwLockReadGuard<'static, T>,
+    _owner: Arc<dyn std::any::Any>,
+}
+
+impl<T> Deref for OwnedPyWrapperReadGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+/// An owned write guard, see [OwnedPyWrapperReadGuard].
+pub struct OwnedPyWrapperWriteGuard<T: 'static> {
+    guard: MappedRwLockWriteGuard<'static, T>,
+    _owner: Arc<dyn std::any::Any>,
+}
+
+impl<T> Deref for OwnedPyWrapperWriteGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for OwnedPyWrapperWriteGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T: 'static> PyWrapper<PyWrapperT1<T>> {
+    /// Like [Self::lock_inner_ref], but returns an [OwnedPyWrapperReadGuard] that keeps
+    /// `self` alive (via the `Arc` it's called through) instead of borrowing from `&self`, so the
+    /// guard can be stored inside a `#[pyclass]`.
+    pub fn try_lock_inner_ref_owned(
+        self: &Arc<Self>,
+    ) -> LockResult<OwnedPyWrapperReadGuard<T>> {
+        let guard = self.inner.try_read_ext()?;
+        let guard = RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap());
+        // SAFETY: see `OwnedPyWrapperReadGuard`'s safety note; `_owner` below keeps `self` (and
+        // so `self.inner`) alive for at least as long as `guard`.
+        let guard = unsafe {
+            std::mem::transmute::<MappedRwLockReadGuard<'_, T>, MappedRwLockReadGuard<'static, T>>(
+                guard,
+            )
+        };
+        Ok(OwnedPyWrapperReadGuard {
+            guard,
+            _owner: self.clone(),
+        })
+    }
+
+    /// Like [Self::lock_inner_mut], but returns an [OwnedPyWrapperWriteGuard]; see
+    /// [Self::try_lock_inner_ref_owned].
+    pub fn try_lock_inner_mut_owned(
+        self: &Arc<Self>,
+    ) -> LockResult<OwnedPyWrapperWriteGuard<T>> {
+        let guard = self.inner.try_write_ext()?;
+        let guard = RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap());
+        // SAFETY: see `try_lock_inner_ref_owned` above.
+        let guard = unsafe {
+            std::mem::transmute::<MappedRwLockWriteGuard<'_, T>, MappedRwLockWriteGuard<'static, T>>(
+                guard,
+            )
+        };
+        Ok(OwnedPyWrapperWriteGuard {
+            guard,
+            _owner: self.clone(),
+        })
+    }
+}
+
+impl<T: 'static> PyWrapper<PyWrapperT2<T>> {
+    /// Like [Self::try_lock_inner_ref], but returns an owned guard; see
+    /// [PyWrapper::<PyWrapperT1<T>>::try_lock_inner_ref_owned]. Keeps [ConsumedError] semantics:
+    /// `Err(ConsumedError)` if the value has already been taken.
+    pub fn try_lock_inner_ref_owned(
+        self: &Arc<Self>,
+    ) -> LockResult<ConsumedResult<OwnedPyWrapperReadGuard<T>>> {
+        let guard = self.inner.try_read_ext()?;
+        if guard.is_err() {
+            return Ok(Err(ConsumedError));
+        }
+        let guard = RwLockReadGuard::map(guard, |inner| inner.as_ref().unwrap());
+        // SAFETY: see `OwnedPyWrapperReadGuard`'s safety note.
+        let guard = unsafe {
+            std::mem::transmute::<MappedRwLockReadGuard<'_, T>, MappedRwLockReadGuard<'static, T>>(
+                guard,
+            )
+        };
+        Ok(Ok(OwnedPyWrapperReadGuard {
+            guard,
+            _owner: self.clone(),
+        }))
+    }
+
+    /// Like [Self::try_lock_inner_mut], but returns an owned guard; see
+    /// [PyWrapper::<PyWrapperT1<T>>::try_lock_inner_ref_owned]. Keeps [ConsumedError] semantics.
+    pub fn try_lock_inner_mut_owned(
+        self: &Arc<Self>,
+    ) -> LockResult<ConsumedResult<OwnedPyWrapperWriteGuard<T>>> {
+        let guard = self.inner.try_write_ext()?;
+        if guard.is_err() {
+            return Ok(Err(ConsumedError));
+        }
+        let guard = RwLockWriteGuard::map(guard, |inner| inner.as_mut().unwrap());
+        // SAFETY: see `OwnedPyWrapperReadGuard`'s safety note.
+        let guard = unsafe {
+            std::mem::transmute::<MappedRwLockWriteGuard<'_, T>, MappedRwLockWriteGuard<'static, T>>(
+                guard,
+            )
+        };
+        Ok(Ok(OwnedPyWrapperWriteGuard {
+            guard,
+            _owner: self.clone(),
+        }))
+    }
+}
+
+impl<T> PyWrapper<PyWrapperT3<T>> {
+    #[inline]
+    pub fn new3(inner: T) -> Self {
+        Self {
+            inner: Mutex::new(Ok(inner)),
+            #[cfg(feature = "debug-lock")]
+            holder: std::sync::atomic::AtomicU64::new(NO_HOLDER),
+        }
+    }
+
+    /// Unlike [PyWrapperT1]/[PyWrapperT2], there's no separate shared-read guard: a `Mutex` is
+    /// always exclusive, so this is the one lock-acquisition method for both reading and writing.
+    pub fn try_lock_inner(&self) -> LockResult<ConsumedResult<MappedMutexGuard<'_, T>>> {
+        self.inner.try_lock_ext().map(|guard| {
+            if guard.is_err() {
+                Err(ConsumedError)
+            } else {
+                // PEFR: it's ok to use [unwrap_unchecked], but i dont like unsafe block
+                Ok(MutexGuard::map(guard, |inner| inner.as_mut().unwrap()))
+            }
+        })
+    }
+
+    pub fn try_take_inner(&self) -> LockResult<ConsumedResult<T>> {
+        self.try_replace_inner(Err(ConsumedError))
+    }
+
+    /// similar to [std::mem::replace]
+    pub fn try_replace_inner(&self, inner: ConsumedResult<T>) -> LockResult<ConsumedResult<T>> {
+        self.inner.try_lock_ext().map(|mut guard| {
+            let result = guard.deref_mut();
+            replace(result, inner)
+        })
+    }
+
+    pub fn try_into_inner(self) -> ConsumedResult<T> {
+        self.inner.into_inner()
+    }
+}
+
 /// This trait allows you to handle [PyWrapperT0] and [PyWrapperT1] with the API of [PyWrapper]<[PyWrapperT2]>,
 /// so you can write future-compatible code.
 ///
@@ -493,13 +1447,13 @@ impl<T> PyWrapperSemverExt for PyWrapper<PyWrapperT1<T>> {
     fn inner_ref_semver(
         &self,
     ) -> LockResult<ConsumedResult<impl MappableDeref<'_, Target = Self::Wrapped>>> {
-        self.lock_inner_ref().map(Ok)
+        self.lock_inner_ref().map(Ok).map_err(Into::into)
     }
 
     fn inner_mut_semver(
         &mut self,
     ) -> LockResult<ConsumedResult<impl MappableDerefMut<'_, Target = Self::Wrapped>>> {
-        self.lock_inner_mut().map(Ok)
+        self.lock_inner_mut().map(Ok).map_err(Into::into)
     }
 
     fn into_inner_semver(self) -> ConsumedResult<Self::Wrapped> {
@@ -513,16 +1467,233 @@ impl<T> PyWrapperSemverExt for PyWrapper<PyWrapperT2<T>> {
     fn inner_ref_semver(
         &self,
     ) -> LockResult<ConsumedResult<impl MappableDeref<'_, Target = Self::Wrapped>>> {
-        self.try_lock_inner_ref()
+        self.try_lock_inner_ref().map_err(Into::into)
     }
 
     fn inner_mut_semver(
         &mut self,
     ) -> LockResult<ConsumedResult<impl MappableDerefMut<'_, Target = Self::Wrapped>>> {
-        self.try_lock_inner_mut()
+        self.try_lock_inner_mut().map_err(Into::into)
     }
 
     fn into_inner_semver(self) -> ConsumedResult<Self::Wrapped> {
         self.try_into_inner()
     }
 }
+
+impl<T> PyWrapperSemverExt for PyWrapper<PyWrapperT3<T>> {
+    type Wrapped = T;
+
+    fn inner_ref_semver(
+        &self,
+    ) -> LockResult<ConsumedResult<impl MappableDeref<'_, Target = Self::Wrapped>>> {
+        self.try_lock_inner()
+    }
+
+    fn inner_mut_semver(
+        &mut self,
+    ) -> LockResult<ConsumedResult<impl MappableDerefMut<'_, Target = Self::Wrapped>>> {
+        self.try_lock_inner()
+    }
+
+    fn into_inner_semver(self) -> ConsumedResult<Self::Wrapped> {
+        self.try_into_inner()
+    }
+}
+
+/// Opt-in `tokio`-backed counterparts of [PyWrapperT1]/[PyWrapperT2], for awaitable pytauri
+/// commands that need exclusive access to wrapped state without blocking a tokio worker thread
+/// (`parking_lot`'s locks are synchronous-only).
+///
+/// Unlike [PyWrapperT1]/[PyWrapperT2], the inner value lives behind an [std::sync::Arc], because
+/// `tokio`'s owned guards (needed so the guard isn't tied to a borrow of `&self`, which an
+/// `.await` point can't hold across) require it.
+#[cfg(feature = "sync")]
+mod async_ext {
+    use std::sync::Arc;
+
+    use tokio::sync::{OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock as AsyncRwLock};
+
+    use super::*;
+
+    /// Can obtain alias references and mutable references, `.await`-ing the lock instead of
+    /// blocking. Requires the `sync` feature.
+    pub type PyWrapperT1Async<T> = Arc<AsyncRwLock<Result<T, Infallible>>>;
+    /// Can obtain alias references, mutable references, and ownership, `.await`-ing the lock
+    /// instead of blocking. Requires the `sync` feature.
+    pub type PyWrapperT2Async<T> = Arc<AsyncRwLock<Result<T, ConsumedError>>>;
+
+    impl<T> sealed::PyWrapperT for PyWrapperT1Async<T> {}
+    impl<T> sealed::PyWrapperT for PyWrapperT2Async<T> {}
+
+    /// An owned read guard yielded by `lock_inner_ref_async`. Not threaded through
+    /// [MappableDeref]: it owns an `Arc` clone rather than borrowing from `&self`, so it doesn't
+    /// fit that abstraction's borrowed-guard shape; it exposes a plain [Deref] instead.
+    pub struct AsyncRefGuard<T, E>(OwnedRwLockReadGuard<Result<T, E>>);
+
+    impl<T, E> Deref for AsyncRefGuard<T, E> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            // invariant: only constructed after checking `is_err()`, see `lock_inner_ref_async` below.
+            self.0.as_ref().unwrap()
+        }
+    }
+
+    /// An owned write guard yielded by `lock_inner_mut_async`, see [AsyncRefGuard].
+    pub struct AsyncMutGuard<T, E>(OwnedRwLockWriteGuard<Result<T, E>>);
+
+    impl<T, E> Deref for AsyncMutGuard<T, E> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            self.0.as_ref().unwrap()
+        }
+    }
+
+    impl<T, E> DerefMut for AsyncMutGuard<T, E> {
+        fn deref_mut(&mut self) -> &mut T {
+            self.0.as_mut().unwrap()
+        }
+    }
+
+    impl<T> PyWrapper<PyWrapperT1Async<T>> {
+        #[inline]
+        pub fn new1_async(inner: T) -> Self {
+            Self {
+                inner: Arc::new(AsyncRwLock::new(Ok(inner))),
+                #[cfg(feature = "debug-lock")]
+                holder: std::sync::atomic::AtomicU64::new(NO_HOLDER),
+            }
+        }
+
+        /// `.await` the lock instead of blocking the current thread, so it's safe to call from
+        /// inside an awaitable pytauri command without starving the tokio runtime.
+        pub async fn lock_inner_ref_async(&self) -> AsyncRefGuard<T, Infallible> {
+            AsyncRefGuard(self.inner.clone().read_owned().await)
+        }
+
+        /// `.await` the lock instead of blocking the current thread.
+        pub async fn lock_inner_mut_async(&self) -> AsyncMutGuard<T, Infallible> {
+            AsyncMutGuard(self.inner.clone().write_owned().await)
+        }
+    }
+
+    impl<T> PyWrapper<PyWrapperT2Async<T>> {
+        #[inline]
+        pub fn new2_async(inner: T) -> Self {
+            Self {
+                inner: Arc::new(AsyncRwLock::new(Ok(inner))),
+                #[cfg(feature = "debug-lock")]
+                holder: std::sync::atomic::AtomicU64::new(NO_HOLDER),
+            }
+        }
+
+        /// `.await` the lock instead of blocking the current thread. Returns [ConsumedError] if
+        /// the value has already been taken, mirroring [PyWrapper::try_lock_inner_ref]'s
+        /// [ConsumedResult] semantics.
+        pub async fn lock_inner_ref_async(&self) -> ConsumedResult<AsyncRefGuard<T, ConsumedError>> {
+            let guard = self.inner.clone().read_owned().await;
+            if guard.is_err() {
+                Err(ConsumedError)
+            } else {
+                Ok(AsyncRefGuard(guard))
+            }
+        }
+
+        /// `.await` the lock instead of blocking the current thread. Returns [ConsumedError] if
+        /// the value has already been taken.
+        pub async fn lock_inner_mut_async(&self) -> ConsumedResult<AsyncMutGuard<T, ConsumedError>> {
+            let guard = self.inner.clone().write_owned().await;
+            if guard.is_err() {
+                Err(ConsumedError)
+            } else {
+                Ok(AsyncMutGuard(guard))
+            }
+        }
+
+        /// `.await` the lock instead of blocking, then take ownership of the inner value,
+        /// mirroring [PyWrapper::try_take_inner].
+        pub async fn take_inner_async(&self) -> ConsumedResult<T> {
+            self.replace_inner_async(Err(ConsumedError)).await
+        }
+
+        /// similar to [std::mem::replace], but `.await`s the lock instead of blocking, mirroring
+        /// [PyWrapper::try_replace_inner].
+        pub async fn replace_inner_async(&self, inner: ConsumedResult<T>) -> ConsumedResult<T> {
+            let mut guard = self.inner.clone().write_owned().await;
+            replace(guard.deref_mut(), inner)
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+pub use async_ext::{AsyncMutGuard, AsyncRefGuard, PyWrapperT1Async, PyWrapperT2Async};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocking_lock_inner_ref_round_trips_the_value() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let wrapper = PyWrapper::<PyWrapperT1<i32>>::new1(42);
+
+            #[cfg(not(feature = "debug-lock"))]
+            let value = *wrapper.blocking_lock_inner_ref(py);
+            #[cfg(feature = "debug-lock")]
+            let value = *wrapper.blocking_lock_inner_ref(py).unwrap();
+
+            assert_eq!(value, 42);
+        });
+    }
+
+    /// Regression test for chunk13-4: a `#[pymethod]` that already holds the write lock (e.g.
+    /// transitively, by calling back into itself) used to deadlock this same thread when it
+    /// called `blocking_lock_inner_mut` again, since `parking_lot::RwLock::write` isn't
+    /// reentrant. With the `debug-lock` feature, that should now surface as
+    /// [DebugLockError::Reentrant] instead of hanging.
+    #[cfg(feature = "debug-lock")]
+    #[test]
+    fn blocking_lock_inner_mut_detects_same_thread_reentrancy_instead_of_deadlocking() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let wrapper = PyWrapper::<PyWrapperT1<i32>>::new1(0);
+            let _outer = wrapper.blocking_lock_inner_mut(py).unwrap();
+
+            let inner = wrapper.blocking_lock_inner_mut(py);
+            assert!(matches!(inner, Err(DebugLockError::Reentrant(_))));
+        });
+    }
+
+    /// Same as above, but for the read-side reentrant call — see
+    /// [blocking_lock_inner_mut_detects_same_thread_reentrancy_instead_of_deadlocking].
+    #[cfg(feature = "debug-lock")]
+    #[test]
+    fn blocking_lock_inner_ref_detects_same_thread_reentrancy_instead_of_deadlocking() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let wrapper = PyWrapper::<PyWrapperT1<i32>>::new1(0);
+            let _outer = wrapper.blocking_lock_inner_mut(py).unwrap();
+
+            let inner = wrapper.blocking_lock_inner_ref(py);
+            assert!(matches!(inner, Err(DebugLockError::Reentrant(_))));
+        });
+    }
+
+    /// Same regression as the [PyWrapperT1] tests above, but for [PyWrapperT2] — see
+    /// [blocking_lock_inner_mut_detects_same_thread_reentrancy_instead_of_deadlocking].
+    #[cfg(feature = "debug-lock")]
+    #[test]
+    fn py_wrapper_t2_blocking_lock_inner_mut_detects_same_thread_reentrancy() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let wrapper = PyWrapper::<PyWrapperT2<i32>>::new2(0);
+            let _outer = wrapper.blocking_lock_inner_mut(py).unwrap().unwrap();
+
+            let inner = wrapper.blocking_lock_inner_mut(py);
+            assert!(matches!(inner, Err(DebugLockError::Reentrant(_))));
+        });
+    }
+}
@@ -1,11 +1,18 @@
-use pyo3::{prelude::*, types::PyBytes};
+use pyo3::{
+    buffer::PyBuffer,
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyBytes, PyMemoryView},
+};
 use tauri::image;
 
+/// `flags` bit requesting a writable buffer, see CPython's `Include/object.h` `PyBUF_WRITABLE`.
+const PY_BUF_WRITABLE: i32 = 0x0001;
+
 /// See also: [tauri::image::Image]
 #[pyclass(frozen, subclass)] // subclass for `pillow`
 #[non_exhaustive]
 pub struct Image {
-    // PERF: maybe we can use `memoryview` or `buffer protocol`.
     rgba: Py<PyBytes>,
     width: u32,
     height: u32,
@@ -47,4 +54,42 @@ impl Image {
     const fn height(&self) -> u32 {
         self.height
     }
+
+    /// Build an [Image] from any object implementing the buffer protocol (e.g. a `numpy`
+    /// array or a `Pillow` image's `tobytes()` buffer), without requiring the caller to
+    /// pre-convert it to [bytes] themselves.
+    #[staticmethod]
+    fn from_buffer(py: Python<'_>, buffer: &Bound<'_, PyAny>, width: u32, height: u32) -> PyResult<Self> {
+        let buffer = PyBuffer::<u8>::get(buffer)?;
+
+        let expected_len = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|len| len.checked_mul(4))
+            .ok_or_else(|| PyValueError::new_err("`width * height * 4` overflowed `usize`"))?;
+        if buffer.len_bytes() != expected_len {
+            return Err(PyValueError::new_err(format!(
+                "buffer has {} bytes, but `width * height * 4` is {expected_len}",
+                buffer.len_bytes()
+            )));
+        }
+        if !buffer.is_c_contiguous() {
+            return Err(PyValueError::new_err("buffer must be C-contiguous"));
+        }
+
+        let rgba = PyBytes::new_with(py, expected_len, |dst| buffer.copy_to_slice(py, dst))?;
+        Ok(Self {
+            rgba: rgba.unbind(),
+            width,
+            height,
+        })
+    }
+
+    /// Python buffer-protocol entry point (see [PEP 688](https://peps.python.org/pep-0688/)),
+    /// letting `memoryview(image)`, `numpy`, and `Pillow` read the RGBA bytes without copying.
+    fn __buffer__<'py>(&self, py: Python<'py>, flags: i32) -> PyResult<Bound<'py, PyMemoryView>> {
+        if flags & PY_BUF_WRITABLE != 0 {
+            return Err(PyValueError::new_err("`Image` buffer is read-only"));
+        }
+        PyMemoryView::from(self.rgba.bind(py).as_any())
+    }
 }
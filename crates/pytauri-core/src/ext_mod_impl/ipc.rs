@@ -1,19 +1,26 @@
-use std::{borrow::Cow, str::FromStr as _};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    str::FromStr as _,
+};
 
 use pyo3::{
     exceptions::PyValueError,
     prelude::*,
-    types::{PyBytes, PyDict, PyMapping, PyString, PyType},
+    types::{PyBytes, PyDict, PyList, PyMapping, PyString, PyType},
+    IntoPyObjectExt as _,
 };
-use pyo3_utils::py_wrapper::{PyWrapper, PyWrapperT0, PyWrapperT2};
+use pyo3_utils::py_wrapper::{PyWrapper, PyWrapperT0, PyWrapperT1, PyWrapperT2};
+use serde_json::Value as JsonValue;
 use tauri::ipc::{
     self, CommandArg as _, CommandItem, InvokeBody, InvokeMessage, InvokeResponseBody,
 };
+use tauri::Manager as _;
 
 use crate::{
     ext_mod_impl::{
         webview::{Webview, WebviewWindow},
-        PyAppHandleExt as _,
+        AppHandle, PyAppHandleExt as _,
     },
     tauri_runtime::Runtime,
     utils::TauriError,
@@ -22,6 +29,136 @@ use crate::{
 type IpcInvoke = tauri::ipc::Invoke<Runtime>;
 type IpcInvokeResolver = tauri::ipc::InvokeResolver<Runtime>;
 type TauriWebviewWindow = tauri::webview::WebviewWindow<Runtime>;
+type TauriWebview = tauri::webview::Webview<Runtime>;
+
+/// A domain's entry in the [RemoteIpcAccessScopes] allowlist: which windows and commands (both
+/// `None` meaning "any") a remote origin on this domain may invoke pyfunc commands from.
+#[derive(Debug, Clone, Default)]
+struct RemoteDomainRule {
+    windows: Option<HashSet<String>>,
+    commands: Option<HashSet<String>>,
+}
+
+/// `tauri::Manager` state, see also [crate::ext_mod_impl::PyAppHandleExt]'s `PyAppHandle`.
+///
+/// Domains are stored lower-cased. Absence of a domain here means "deny", matching this
+/// subsystem's default-deny-remote posture (mirrors Tauri's `dangerousRemoteDomainIpcAccess`,
+/// but enforced at the pytauri dispatch layer instead of the webview manifest).
+struct RemoteIpcAccessScopes(PyWrapper<PyWrapperT1<HashMap<String, RemoteDomainRule>>>);
+
+impl Default for RemoteIpcAccessScopes {
+    fn default() -> Self {
+        Self(PyWrapper::new1(HashMap::new()))
+    }
+}
+
+/// How a webview's current URL relates to the remote-domain allowlist.
+enum OriginKind {
+    /// `tauri://`, `ipc://`, or `http://localhost`/`http://tauri.localhost`: always permitted.
+    Local,
+    /// A `http(s)` origin with a host, subject to the allowlist.
+    Remote(String),
+    /// `about:blank`, `data:`, `file:`, or any other scheme not explicitly recognized as [Local]
+    /// or [Remote] (including a custom protocol we don't have a rule for): always denied.
+    Opaque,
+}
+
+fn classify_origin(url: &tauri::Url) -> OriginKind {
+    match url.scheme() {
+        "tauri" | "ipc" => OriginKind::Local,
+        "http" | "https" => match url.host_str() {
+            // `tauri.localhost` is the custom-protocol host tauri uses on Windows/Linux.
+            Some(host) if host.eq_ignore_ascii_case("localhost") || host.eq_ignore_ascii_case("tauri.localhost") => {
+                OriginKind::Local
+            }
+            Some(host) => OriginKind::Remote(host.to_ascii_lowercase()),
+            None => OriginKind::Opaque,
+        },
+        // Any scheme other than the ones explicitly recognized above (`tauri`/`ipc`'s custom
+        // protocol, or `http`/`https`) is treated as opaque and denied by default — this
+        // includes `about:blank`, `data:`, `file:`, `javascript:`, and any other app-registered
+        // custom protocol we don't yet have a rule for. Apps needing to trust a custom protocol
+        // should extend this match, not fall through to it.
+        _ => OriginKind::Opaque,
+    }
+}
+
+fn json_value_to_py(py: Python<'_>, value: &JsonValue) -> PyResult<PyObject> {
+    Ok(match value {
+        JsonValue::Null => py.None(),
+        JsonValue::Bool(b) => b.into_py_any(py)?,
+        JsonValue::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                i.into_py_any(py)?
+            } else if let Some(u) = n.as_u64() {
+                u.into_py_any(py)?
+            } else {
+                n.as_f64().unwrap_or_default().into_py_any(py)?
+            }
+        }
+        JsonValue::String(s) => s.into_py_any(py)?,
+        JsonValue::Array(array) => {
+            let list = PyList::empty(py);
+            for item in array {
+                list.append(json_value_to_py(py, item)?)?;
+            }
+            list.into_py_any(py)?
+        }
+        JsonValue::Object(object) => {
+            let dict = PyDict::new(py);
+            for (key, value) in object {
+                dict.set_item(key, json_value_to_py(py, value)?)?;
+            }
+            dict.into_py_any(py)?
+        }
+    })
+}
+
+/// Serialize an arbitrary Python object to a JSON string via the `json` module, for building
+/// [InvokeResponseBody::Json] payloads (as opposed to [InvokeResponseBody::Raw] bytes), or any
+/// other JSON-shaped payload Python needs to hand to Rust (see [crate::ext_mod_impl::Emitter]).
+pub(crate) fn py_to_json_string(py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<String> {
+    py.import("json")?.call_method1("dumps", (value,))?.extract()
+}
+
+/// Convert a capability scope's entries (as yielded by [tauri::ipc::CommandScope::allows]/
+/// [tauri::ipc::CommandScope::denies] and their [tauri::ipc::GlobalScope] counterparts) into
+/// Python objects.
+fn scope_entries_to_py<X>(
+    py: Python<'_>,
+    entries: impl IntoIterator<Item = X>,
+) -> PyResult<Vec<PyObject>>
+where
+    X: std::borrow::Borrow<JsonValue>,
+{
+    entries
+        .into_iter()
+        .map(|entry| json_value_to_py(py, entry.borrow()))
+        .collect()
+}
+
+/// A parsed Tauri capability scope, with its entries JSON-decoded into Python objects.
+///
+/// See also: [tauri::ipc::CommandScope] and [tauri::ipc::GlobalScope].
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct ScopeObject {
+    allows: Vec<PyObject>,
+    denies: Vec<PyObject>,
+}
+
+#[pymethods]
+impl ScopeObject {
+    /// The entries this scope explicitly allows.
+    fn allows(&self, py: Python<'_>) -> Vec<PyObject> {
+        self.allows.iter().map(|value| value.clone_ref(py)).collect()
+    }
+
+    /// The entries this scope explicitly denies.
+    fn denies(&self, py: Python<'_>) -> Vec<PyObject> {
+        self.denies.iter().map(|value| value.clone_ref(py)).collect()
+    }
+}
 
 /// Please refer to the Python-side documentation
 // `subclass` for Generic type hint
@@ -55,8 +192,19 @@ impl InvokeResolver {
         })
     }
 
-    // TODO: Support more Python types. Tauri seems to only support `serde` types,
-    // and not `Raw: [u8]`. We should open an issue to ask them about this.
+    /// Like [Self::resolve], but accepts an arbitrary Python object and resolves it as a
+    /// JSON-encoded [InvokeResponseBody::Json], instead of requiring the caller to hand-encode
+    /// [bytes] themselves.
+    fn resolve_json(&self, py: Python<'_>, value: Bound<'_, PyAny>) -> PyResult<()> {
+        // NOTE: This function implementation must not block
+        let json = py_to_json_string(py, &value)?;
+        py.allow_threads(|| {
+            let resolver = self.inner.try_take_inner()??;
+            resolver.resolve(InvokeResponseBody::Json(json));
+            Ok(())
+        })
+    }
+
     fn reject(&self, py: Python<'_>, value: Cow<'_, str>) -> PyResult<()> {
         // NOTE: This function implementation must not block
         py.allow_threads(|| {
@@ -87,6 +235,10 @@ impl Invoke {
                 return None;
             }
         };
+        if let Err(e) = Self::check_remote_ipc_access(invoke.message.webview_ref(), func_name) {
+            invoke.resolver.reject(e);
+            return None;
+        }
         // TODO, PERF: may be we should use [PyString::intern] ?
         let command = PyString::new(py, func_name).unbind();
 
@@ -109,6 +261,48 @@ impl Invoke {
             .map_err(|e| format!("{e}"))?;
         Ok(func_name)
     }
+
+    /// Enforce the [RemoteIpcAccessScopes] allowlist against the webview's current URL, denying
+    /// by default unless the origin is local or its domain has been registered via
+    /// [Invoke::set_remote_ipc_access].
+    fn check_remote_ipc_access(webview: &TauriWebview, func_name: &str) -> Result<(), String> {
+        let url = webview
+            .url()
+            .map_err(|e| format!("failed to read the webview's current URL: {e}"))?;
+
+        let host = match classify_origin(&url) {
+            OriginKind::Local => return Ok(()),
+            OriginKind::Opaque => {
+                return Err(format!(
+                    "remote IPC access denied: `{url}` is an opaque origin, so it cannot invoke pyfunc commands"
+                ))
+            }
+            OriginKind::Remote(host) => host,
+        };
+
+        let rule = webview
+            .try_state::<RemoteIpcAccessScopes>()
+            .and_then(|scopes| scopes.inner().0.lock_inner_ref().ok()?.get(&host).cloned());
+        let Some(rule) = rule else {
+            return Err(format!(
+                "remote IPC access denied: domain `{host}` is not registered in the allowlist \
+                 (see `Invoke.set_remote_ipc_access`)"
+            ));
+        };
+
+        let label = webview.label();
+        if rule.windows.is_some_and(|windows| !windows.contains(label)) {
+            return Err(format!(
+                "remote IPC access denied: domain `{host}` is not allowed to invoke commands in window `{label}`"
+            ));
+        }
+        if rule.commands.is_some_and(|commands| !commands.contains(func_name)) {
+            return Err(format!(
+                "remote IPC access denied: domain `{host}` is not allowed to invoke command `{func_name}`"
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[pymethods]
@@ -118,6 +312,9 @@ impl Invoke {
     const BODY_KEY: &str = "body";
     const APP_HANDLE_KEY: &str = "app_handle";
     const WEBVIEW_WINDOW_KEY: &str = "webview_window";
+    const COMMAND_SCOPE_KEY: &str = "command_scope";
+    const GLOBAL_SCOPE_KEY: &str = "global_scope";
+    const CHANNEL_KEY: &str = "channel";
 
     /// Pass in a Python dictionary, which can contain the following
     /// optional keys (values are arbitrary):
@@ -125,6 +322,11 @@ impl Invoke {
     /// - [Self::BODY_KEY] : [PyBytes]
     /// - [Self::APP_HANDLE_KEY] : [crate::ext_mod::AppHandle]
     /// - [Self::WEBVIEW_WINDOW_KEY] : [crate::ext_mod::webview::WebviewWindow]
+    /// - [Self::COMMAND_SCOPE_KEY] : [ScopeObject], from [tauri::ipc::CommandScope]
+    /// - [Self::GLOBAL_SCOPE_KEY] : [ScopeObject], from [tauri::ipc::GlobalScope]
+    /// - [Self::CHANNEL_KEY] : [Channel], parsed from the frontend's `channel` argument the same
+    ///   way [tauri::ipc::Channel::from_command] does, so streaming commands don't have to
+    ///   reconstruct it via [JavaScriptChannelId::from_str] + [JavaScriptChannelId::channel_on]
     ///
     /// # Returns
     ///
@@ -188,6 +390,73 @@ impl Invoke {
             arguments.set_item(Self::WEBVIEW_WINDOW_KEY, WebviewWindow::new(webview_window))?;
         }
 
+        if parameters.contains(Self::COMMAND_SCOPE_KEY)? {
+            let command_name = self.command.bind(py).to_str()?;
+            let command_scope_item = CommandItem {
+                plugin: None,
+                name: command_name,
+                key: "__whatever__commandScope",
+                message: &message,
+                acl: &acl,
+            };
+            let command_scope = match tauri::ipc::CommandScope::<JsonValue>::from_command(
+                command_scope_item,
+            ) {
+                Ok(command_scope) => command_scope,
+                Err(e) => {
+                    resolver.invoke_error(e);
+                    return Ok(None);
+                }
+            };
+            let scope_object = ScopeObject {
+                allows: scope_entries_to_py(py, command_scope.allows())?,
+                denies: scope_entries_to_py(py, command_scope.denies())?,
+            };
+            arguments.set_item(Self::COMMAND_SCOPE_KEY, scope_object)?;
+        }
+
+        if parameters.contains(Self::GLOBAL_SCOPE_KEY)? {
+            let command_name = self.command.bind(py).to_str()?;
+            let global_scope_item = CommandItem {
+                plugin: None,
+                name: command_name,
+                key: "__whatever__globalScope",
+                message: &message,
+                acl: &acl,
+            };
+            let global_scope =
+                match tauri::ipc::GlobalScope::<JsonValue>::from_command(global_scope_item) {
+                    Ok(global_scope) => global_scope,
+                    Err(e) => {
+                        resolver.invoke_error(e);
+                        return Ok(None);
+                    }
+                };
+            let scope_object = ScopeObject {
+                allows: scope_entries_to_py(py, global_scope.allows())?,
+                denies: scope_entries_to_py(py, global_scope.denies())?,
+            };
+            arguments.set_item(Self::GLOBAL_SCOPE_KEY, scope_object)?;
+        }
+
+        if parameters.contains(Self::CHANNEL_KEY)? {
+            let channel_item = CommandItem {
+                plugin: None,
+                name: "__whatever__pyfunc",
+                key: Self::CHANNEL_KEY,
+                message: &message,
+                acl: &acl,
+            };
+            let channel = match ipc::Channel::from_command(channel_item) {
+                Ok(channel) => channel,
+                Err(e) => {
+                    resolver.invoke_error(e);
+                    return Ok(None);
+                }
+            };
+            arguments.set_item(Self::CHANNEL_KEY, Channel::new(channel))?;
+        }
+
         Ok(Some(InvokeResolver::new(resolver, arguments.unbind())))
     }
 
@@ -201,8 +470,20 @@ impl Invoke {
         })
     }
 
-    // TODO: Support more Python types. Tauri seems to only support `serde` types,
-    // and not `Raw: [u8]`. We should open an issue to ask them about this.
+    /// Like [Self::resolve], but accepts an arbitrary Python object and resolves it as a
+    /// JSON-encoded [InvokeResponseBody::Json], instead of requiring the caller to hand-encode
+    /// [bytes] themselves.
+    fn resolve_json(&self, py: Python<'_>, value: Bound<'_, PyAny>) -> PyResult<()> {
+        // NOTE: This function implementation must not block
+
+        let json = py_to_json_string(py, &value)?;
+        py.allow_threads(|| {
+            let resolver = self.inner.try_take_inner()??.resolver;
+            resolver.resolve(InvokeResponseBody::Json(json));
+            Ok(())
+        })
+    }
+
     fn reject(&self, py: Python<'_>, value: Cow<'_, str>) -> PyResult<()> {
         // NOTE: This function implementation must not block
 
@@ -212,6 +493,44 @@ impl Invoke {
             Ok(())
         })
     }
+
+    /// Allow a remote `http(s)` domain to invoke pyfunc commands, optionally scoped to specific
+    /// window labels and/or command names (omit to allow any window/command for this domain).
+    ///
+    /// Local origins (`tauri://`, `ipc://`, `http(s)://localhost`, and tauri's custom-protocol
+    /// host `http(s)://tauri.localhost`) never need an entry here, they are always permitted;
+    /// every other remote `http(s)` domain is denied by default until registered with this
+    /// method. Origins with any other scheme (including app-registered custom protocols) are
+    /// always denied and cannot be registered here.
+    #[staticmethod]
+    #[pyo3(signature = (app_handle, domain, *, windows=None, commands=None))]
+    fn set_remote_ipc_access(
+        app_handle: Py<AppHandle>,
+        domain: &str,
+        windows: Option<Vec<String>>,
+        commands: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        let app_handle = app_handle.get();
+        let app_handle = app_handle.0.inner_ref();
+
+        if app_handle.try_state::<RemoteIpcAccessScopes>().is_none() {
+            app_handle.manage(RemoteIpcAccessScopes::default());
+        }
+        let scopes = app_handle
+            .try_state::<RemoteIpcAccessScopes>()
+            .expect("just unconditionally `manage`d above");
+
+        let rule = RemoteDomainRule {
+            windows: windows.map(|windows| windows.into_iter().collect()),
+            commands: commands.map(|commands| commands.into_iter().collect()),
+        };
+        scopes
+            .inner()
+            .0
+            .lock_inner_mut()?
+            .insert(domain.to_ascii_lowercase(), rule);
+        Ok(())
+    }
 }
 
 /// see also: [tauri::ipc::JavaScriptChannelId]
@@ -283,6 +602,22 @@ impl Channel {
             Ok(())
         })
     }
+
+    /// Like [Self::send], but accepts an arbitrary Python object and sends it as a JSON-encoded
+    /// [InvokeResponseBody::Json], instead of requiring the caller to hand-encode [bytes]
+    /// themselves.
+    fn send_json(&self, py: Python<'_>, value: Bound<'_, PyAny>) -> PyResult<()> {
+        let json = py_to_json_string(py, &value)?;
+        // [tauri::ipc::Channel::send] is not a very fast operation,
+        // so we need to release the GIL
+        py.allow_threads(|| {
+            self.0
+                .inner_ref()
+                .send(InvokeResponseBody::Json(json))
+                .map_err(TauriError::from)?;
+            Ok(())
+        })
+    }
 }
 
 // You can enable this comment and expand the macro
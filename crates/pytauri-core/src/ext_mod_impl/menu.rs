@@ -1,14 +1,23 @@
-use std::ops::Deref;
-
-use pyo3::{marker::Ungil, prelude::*, types::PyString};
+use std::{collections::HashMap, ops::Deref};
+
+use parking_lot::Mutex;
+use pyo3::{
+    exceptions::{PyNotImplementedError, PyValueError},
+    marker::Ungil,
+    prelude::*,
+    types::PyString,
+};
 use pyo3_utils::{
-    py_wrapper::{PyWrapper, PyWrapperT0},
+    py_wrapper::{PyWrapper, PyWrapperT0, PyWrapperT1},
     ungil::UnsafeUngilExt,
 };
-use tauri::menu::{self, ContextMenu as _, IsMenuItem, MenuId};
+use tauri::{
+    menu::{self, ContextMenu as _, IsMenuItem, MenuId},
+    Manager as _,
+};
 
 use crate::{
-    ext_mod_impl::{self, ImplManager, PyAppHandleExt as _},
+    ext_mod_impl::{self, ImplManager, PyAppHandleExt as _, TauriAppHandle},
     manager_method_impl,
     tauri_runtime::Runtime,
     utils::TauriError,
@@ -32,6 +41,161 @@ pub type MenuID = PyString;
 pub type MenuEvent = MenuID;
 pub use menu::{HELP_SUBMENU_ID, WINDOW_SUBMENU_ID};
 
+/// A registered per-item handler, plus whatever extra context [MenuItemHandlers::dispatch]
+/// needs to pass to it beyond the usual `(app_handle, menu_event)`.
+enum HandlerEntry {
+    /// [MenuItem]/[IconMenuItem]: called as `(app_handle, menu_event)`.
+    Plain(Py<PyAny>),
+    /// [CheckMenuItem]: called as `(app_handle, menu_event, checked)`, where `checked` is
+    /// the item's state *after* the click that fired the event.
+    Check(Py<PyAny>, Py<CheckMenuItem>),
+}
+
+impl HandlerEntry {
+    fn clone_ref(&self, py: Python<'_>) -> Self {
+        match self {
+            Self::Plain(callback) => Self::Plain(callback.clone_ref(py)),
+            Self::Check(callback, item) => Self::Check(callback.clone_ref(py), item.clone_ref(py)),
+        }
+    }
+}
+
+/// Per-item click handlers, keyed by [MenuId], for [MenuItem]/[CheckMenuItem]/[IconMenuItem].
+///
+/// Registered via each item's `handler` constructor argument or `set_handler` method, and
+/// consulted by [Self::dispatch] before the app-wide/window-scoped handler or stream, so a
+/// single item can be handled without maintaining a manual id → callback table. Every entry
+/// point that registers a Tauri `on_menu_event` callback —
+/// [ext_mod_impl::AppHandle::on_menu_event], [ext_mod_impl::WebviewWindow::on_menu_event], and
+/// [ext_mod_impl::Manager::menu_events] — calls [Self::dispatch] first; Tauri's own
+/// `on_menu_event` registration is last-call-wins, so if only one of them called through here
+/// the other two would silently stop seeing per-item handlers the moment a later entry point
+/// was registered. Registering a handler for an `id` that already has one (e.g. via `with_id`)
+/// overwrites it — last-writer-wins.
+///
+/// Entries are removed when the item is taken out of its menu via `remove`/`remove_at`, but
+/// are otherwise kept for the lifetime of the app: the same native item can be reached
+/// through multiple Python wrapper objects (see [MenuItemKind::from_tauri]), so tying
+/// cleanup to a single wrapper's `__del__` would risk dropping a handler that's still in use.
+#[derive(Default)]
+pub(crate) struct MenuItemHandlers(Mutex<HashMap<String, HandlerEntry>>);
+
+impl MenuItemHandlers {
+    fn state(manager: &impl tauri::Manager<Runtime>) -> tauri::State<'_, Self> {
+        if manager.try_state::<Self>().is_none() {
+            manager.manage(Self::default());
+        }
+        manager
+            .try_state::<Self>()
+            .expect("just managed above, so this never fails")
+    }
+
+    fn set(manager: &impl tauri::Manager<Runtime>, id: &MenuId, handler: Py<PyAny>) {
+        Self::state(manager)
+            .0
+            .lock()
+            .insert(id.0.clone(), HandlerEntry::Plain(handler));
+    }
+
+    fn set_check(
+        manager: &impl tauri::Manager<Runtime>,
+        id: &MenuId,
+        handler: Py<PyAny>,
+        item: Py<CheckMenuItem>,
+    ) {
+        Self::state(manager)
+            .0
+            .lock()
+            .insert(id.0.clone(), HandlerEntry::Check(handler, item));
+    }
+
+    fn unset(manager: &impl tauri::Manager<Runtime>, id: &str) {
+        if let Some(state) = manager.try_state::<Self>() {
+            state.0.lock().remove(id);
+        }
+    }
+
+    /// Look up `id` in the registry and, if a handler is registered, invoke it under the GIL
+    /// the caller already holds (`py`). See [HandlerEntry] for the call signature of each variant.
+    pub(crate) fn dispatch(app_handle: &TauriAppHandle, py: Python<'_>, id: &str) {
+        let entry = app_handle
+            .try_state::<Self>()
+            .and_then(|state| state.0.lock().get(id).map(|entry| entry.clone_ref(py)));
+
+        let Some(entry) = entry else {
+            return;
+        };
+
+        let py_app_handle = app_handle.py_app_handle();
+        let menu_event: Bound<'_, MenuEvent> = MenuEvent::intern(py, id);
+
+        let (callback, result) = match entry {
+            HandlerEntry::Plain(callback) => {
+                let bound = callback.bind(py).clone();
+                let result = bound.call1((py_app_handle, menu_event));
+                (bound, result)
+            }
+            HandlerEntry::Check(callback, item) => {
+                let checked = item.get().0.inner_ref().is_checked().unwrap_or(false);
+                let bound = callback.bind(py).clone();
+                let result = bound.call1((py_app_handle, menu_event, checked));
+                (bound, result)
+            }
+        };
+        if let Err(e) = result {
+            e.write_unraisable(py, Some(&callback));
+        }
+    }
+}
+
+/// An `asyncio`-backed subscription to a manager's menu events, returned by
+/// [ext_mod_impl::Manager::menu_events].
+///
+/// `async for event in stream` yields interned [MenuEvent]s as Tauri activates menu items,
+/// as an alternative to registering a synchronous callback (see
+/// [ext_mod_impl::AppHandle::on_menu_event] and [MenuItemHandlers] for that). Backed by an
+/// `asyncio.Queue`: the Tauri-side callback only acquires the GIL to `put_nowait` the event,
+/// dropping it (rather than blocking Tauri's event-dispatch thread) if the queue is full —
+/// see `maxsize` on [ext_mod_impl::Manager::menu_events].
+///
+/// NOTE: unlike [MenuItemHandlers], there is no registry mapping an arbitrary fired [MenuId]
+/// back to its item, so a [CheckMenuItem]'s resulting checked state is not included here;
+/// query [CheckMenuItem::is_checked] yourself if you need it.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct MenuEventStream {
+    queue: Py<PyAny>,
+}
+
+impl MenuEventStream {
+    pub(crate) fn new(py: Python<'_>, maxsize: usize) -> PyResult<Self> {
+        let queue = py
+            .import("asyncio")?
+            .call_method1("Queue", (maxsize,))?
+            .unbind();
+        Ok(Self { queue })
+    }
+
+    pub(crate) fn push(&self, py: Python<'_>, id: &str) {
+        let queue = self.queue.bind(py);
+        let event = MenuEvent::intern(py, id);
+        if let Err(e) = queue.call_method1("put_nowait", (event,)) {
+            e.write_unraisable(py, Some(queue));
+        }
+    }
+}
+
+#[pymethods]
+impl MenuEventStream {
+    fn __aiter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.queue.bind(py).call_method0("get")
+    }
+}
+
 /// See also: [tauri::menu::MenuItemKind].
 #[derive(FromPyObject, IntoPyObject, IntoPyObjectRef)]
 #[non_exhaustive]
@@ -153,6 +317,11 @@ impl MenuItemKind {
     fn remove_from_menu(&self, menu: &impl TauriMenuProto) -> tauri::Result<()> {
         self.delegate_inner_ref(|item| menu.remove(item))
     }
+
+    #[inline]
+    fn id_string(&self) -> String {
+        self.delegate_inner_ref(|item| item.id().0.clone())
+    }
 }
 
 impl MenuItemKind {
@@ -184,7 +353,222 @@ impl MenuItemKind {
     }
 }
 
+fn get_opt<'py, T: FromPyObject<'py>>(spec: &Bound<'py, PyAny>, key: &str) -> PyResult<Option<T>> {
+    match spec.get_item(key) {
+        Ok(value) if !value.is_none() => value.extract().map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn get_req<'py, T: FromPyObject<'py>>(spec: &Bound<'py, PyAny>, key: &str) -> PyResult<T> {
+    get_opt(spec, key)?.ok_or_else(|| {
+        PyValueError::new_err(format!("menu spec node is missing required field `{key}`"))
+    })
+}
+
+/// Which [PredefinedMenuItem] a `"predefined"` [MenuSpecNode] builds, selected by its `text`
+/// field (e.g. `{"kind": "predefined", "text": "separator"}`).
+#[derive(Clone, Copy)]
+enum PredefinedSpec {
+    Separator,
+    Copy,
+    Cut,
+    Paste,
+    SelectAll,
+    Undo,
+    Redo,
+    Minimize,
+    Maximize,
+    Fullscreen,
+    Hide,
+    HideOthers,
+    ShowAll,
+    CloseWindow,
+    Quit,
+    Services,
+}
+
+impl PredefinedSpec {
+    fn parse(name: &str) -> PyResult<Self> {
+        Ok(match name {
+            "separator" => Self::Separator,
+            "copy" => Self::Copy,
+            "cut" => Self::Cut,
+            "paste" => Self::Paste,
+            "select_all" => Self::SelectAll,
+            "undo" => Self::Undo,
+            "redo" => Self::Redo,
+            "minimize" => Self::Minimize,
+            "maximize" => Self::Maximize,
+            "fullscreen" => Self::Fullscreen,
+            "hide" => Self::Hide,
+            "hide_others" => Self::HideOthers,
+            "show_all" => Self::ShowAll,
+            "close_window" => Self::CloseWindow,
+            "quit" => Self::Quit,
+            "services" => Self::Services,
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown predefined menu item `{other}`, expected one of: separator, copy, \
+                     cut, paste, select_all, undo, redo, minimize, maximize, fullscreen, hide, \
+                     hide_others, show_all, close_window, quit, services"
+                )))
+            }
+        })
+    }
+
+    fn build(self, py: Python<'_>, manager: &ImplManager, text: &str) -> PyResult<PredefinedMenuItem> {
+        // an empty `text` falls back to tauri's platform-default label.
+        let text = (!text.is_empty()).then_some(text);
+        manager_method_impl!(py, manager, |py, manager| PredefinedMenuItem::delegate_inner(
+            py,
+            manager,
+            move |manager| match self {
+                Self::Separator => TauriPredefinedMenuItem::separator(manager),
+                Self::Copy => TauriPredefinedMenuItem::copy(manager, text),
+                Self::Cut => TauriPredefinedMenuItem::cut(manager, text),
+                Self::Paste => TauriPredefinedMenuItem::paste(manager, text),
+                Self::SelectAll => TauriPredefinedMenuItem::select_all(manager, text),
+                Self::Undo => TauriPredefinedMenuItem::undo(manager, text),
+                Self::Redo => TauriPredefinedMenuItem::redo(manager, text),
+                Self::Minimize => TauriPredefinedMenuItem::minimize(manager, text),
+                Self::Maximize => TauriPredefinedMenuItem::maximize(manager, text),
+                Self::Fullscreen => TauriPredefinedMenuItem::fullscreen(manager, text),
+                Self::Hide => TauriPredefinedMenuItem::hide(manager, text),
+                Self::HideOthers => TauriPredefinedMenuItem::hide_others(manager, text),
+                Self::ShowAll => TauriPredefinedMenuItem::show_all(manager, text),
+                Self::CloseWindow => TauriPredefinedMenuItem::close_window(manager, text),
+                Self::Quit => TauriPredefinedMenuItem::quit(manager, text),
+                Self::Services => TauriPredefinedMenuItem::services(manager, text),
+            }
+        ))?
+    }
+}
+
+enum MenuSpecKind {
+    Item,
+    Check(bool),
+    Icon(Option<NativeIcon>),
+    Predefined(PredefinedSpec),
+    Submenu(Vec<MenuSpecNode>),
+}
+
+/// One node of the nested Python structure accepted by [Menu::from_spec]/[Submenu::from_spec]:
+/// a dict with a `kind` (`"item"`, `"check"`, `"icon"`, `"predefined"`, or `"submenu"`), plus
+/// whichever of `id`, `text`, `enabled`, `accelerator`, `checked`, `icon`, `items` that `kind` uses.
+struct MenuSpecNode {
+    id: Option<String>,
+    text: String,
+    enabled: bool,
+    accelerator: Option<String>,
+    kind: MenuSpecKind,
+}
+
+impl MenuSpecNode {
+    fn parse(spec: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let kind: String = get_req(spec, "kind")?;
+        let id: Option<String> = get_opt(spec, "id")?;
+        let text: String = get_opt(spec, "text")?.unwrap_or_default();
+        let enabled: bool = get_opt(spec, "enabled")?.unwrap_or(true);
+        let accelerator: Option<String> = get_opt(spec, "accelerator")?;
+
+        let kind = match kind.as_str() {
+            "item" => MenuSpecKind::Item,
+            "check" => MenuSpecKind::Check(get_opt(spec, "checked")?.unwrap_or(false)),
+            "icon" => MenuSpecKind::Icon(get_opt(spec, "icon")?),
+            "predefined" => MenuSpecKind::Predefined(PredefinedSpec::parse(&text)?),
+            "submenu" => {
+                let items: Vec<Bound<'_, PyAny>> = get_opt(spec, "items")?.unwrap_or_default();
+                let items = items.iter().map(Self::parse).collect::<PyResult<Vec<_>>>()?;
+                MenuSpecKind::Submenu(items)
+            }
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown menu spec node kind `{other}`, expected one of: item, check, icon, \
+                     predefined, submenu"
+                )))
+            }
+        };
+
+        Ok(Self {
+            id,
+            text,
+            enabled,
+            accelerator,
+            kind,
+        })
+    }
+
+    /// Recursively construct this node (and any `submenu` children) via the same
+    /// per-item constructors used by [MenuBuilder]/[SubmenuBuilder], so the whole
+    /// tree is built in the single call made by [Menu::from_spec]/[Submenu::from_spec].
+    fn build(&self, py: Python<'_>, manager: &ImplManager) -> PyResult<MenuItemKind> {
+        let id = self.id.clone().map(MenuId);
+        let accelerator = self.accelerator.as_deref();
+
+        let item_kind = match &self.kind {
+            MenuSpecKind::Item => {
+                let item = manager_method_impl!(py, manager, |py, manager| {
+                    MenuItem::new_impl(py, manager, &self.text, self.enabled, accelerator, id, None)
+                })??;
+                MenuItemKind::MenuItem(item.into_pyobject(py)?.unbind())
+            }
+            MenuSpecKind::Check(checked) => {
+                let item = manager_method_impl!(py, manager, |py, manager| {
+                    CheckMenuItem::new_impl(
+                        py,
+                        manager,
+                        &self.text,
+                        self.enabled,
+                        *checked,
+                        accelerator,
+                        id,
+                        None,
+                    )
+                })??;
+                MenuItemKind::Check(item.into_pyobject(py)?.unbind())
+            }
+            MenuSpecKind::Icon(native_icon) => {
+                let icon = IconOrNative::Native((*native_icon).map(Into::into));
+                let item = manager_method_impl!(py, manager, |py, manager| {
+                    IconMenuItem::new_impl(
+                        py,
+                        manager,
+                        &self.text,
+                        self.enabled,
+                        icon,
+                        accelerator,
+                        id,
+                        None,
+                    )
+                })??;
+                MenuItemKind::Icon(item.into_pyobject(py)?.unbind())
+            }
+            MenuSpecKind::Predefined(predefined) => {
+                let item = predefined.build(py, manager, &self.text)?;
+                MenuItemKind::Predefined(item.into_pyobject(py)?.unbind())
+            }
+            MenuSpecKind::Submenu(children) => {
+                let items = children
+                    .iter()
+                    .map(|child| child.build(py, manager))
+                    .collect::<PyResult<Vec<_>>>()?;
+                let submenu = manager_method_impl!(py, manager, |py, manager| {
+                    Submenu::new_impl(py, manager, &self.text, self.enabled, id, Some(items))
+                })??;
+                MenuItemKind::Submenu(submenu.into_pyobject(py)?.unbind())
+            }
+        };
+
+        Ok(item_kind)
+    }
+}
+
 /// see also: [tauri::menu::Menu]
+///
+/// To react when a user clicks one of this menu's items, register a callback with
+/// [ext_mod_impl::AppHandle::on_menu_event] (app-wide) or a per-item `handler`/`set_handler`
+/// (see [MenuItemHandlers]).
 #[pyclass(frozen)]
 #[non_exhaustive]
 pub struct Menu(pub PyWrapper<PyWrapperT0<TauriMenu>>);
@@ -272,6 +656,32 @@ impl Menu {
         ))?
     }
 
+    /// Build a whole [Menu] tree from a nested Python structure in one call.
+    ///
+    /// `spec` is a dict with an optional `id` and an `items` list, each entry of which is a
+    /// node as described by [MenuSpecNode]. See also: [MenuBuilder], for building a menu
+    /// item-by-item instead.
+    #[staticmethod]
+    fn from_spec(py: Python<'_>, manager: ImplManager, spec: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let id: Option<String> = get_opt(spec, "id")?;
+        let items: Vec<Bound<'_, PyAny>> = get_opt(spec, "items")?.unwrap_or_default();
+        let items = items
+            .iter()
+            .map(MenuSpecNode::parse)
+            .collect::<PyResult<Vec<_>>>()?;
+        let items = items
+            .iter()
+            .map(|item| item.build(py, &manager))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        manager_method_impl!(py, &manager, |py, manager| Self::new_impl(
+            py,
+            manager,
+            id.clone().map(MenuId),
+            Some(items)
+        ))?
+    }
+
     #[staticmethod]
     fn default(py: Python<'_>, app_handle: Py<ext_mod_impl::AppHandle>) -> PyResult<Self> {
         py.allow_threads(|| {
@@ -293,6 +703,43 @@ impl Menu {
         MenuID::intern(py, &menu.id().0)
     }
 
+    /// Show this menu as a native context (right-click) menu on `window`.
+    ///
+    /// `window` may be either a [ext_mod_impl::window::Window] or a
+    /// [ext_mod_impl::webview::WebviewWindow].
+    ///
+    /// See also: [tauri::menu::ContextMenu::popup].
+    fn popup(&self, py: Python<'_>, window: ImplWindow) -> PyResult<()> {
+        py.allow_threads(|| {
+            let menu = self.0.inner_ref();
+            popup_window_impl!(&window, |window| {
+                menu.popup(window).map_err(TauriError::from)
+            })?;
+            Ok(())
+        })
+    }
+
+    /// Show this menu as a native context (right-click) menu on `window`, at `position`.
+    ///
+    /// `window` may be either a [ext_mod_impl::window::Window] or a
+    /// [ext_mod_impl::webview::WebviewWindow].
+    ///
+    /// See also: [tauri::menu::ContextMenu::popup_at].
+    fn popup_at(
+        &self,
+        py: Python<'_>,
+        window: ImplWindow,
+        position: ext_mod_impl::Position,
+    ) -> PyResult<()> {
+        py.allow_threads(|| {
+            let menu = self.0.inner_ref();
+            popup_window_impl!(&window, |window| {
+                menu.popup_at(window, position).map_err(TauriError::from)
+            })?;
+            Ok(())
+        })
+    }
+
     fn append(&self, py: Python<'_>, item: MenuItemKind) -> PyResult<()> {
         py.allow_threads(|| {
             let menu = self.0.inner_ref();
@@ -357,6 +804,7 @@ impl Menu {
             let menu = self.0.inner_ref();
             item.remove_from_menu(menu.deref())
                 .map_err(TauriError::from)?;
+            MenuItemHandlers::unset(menu.app_handle(), &item.id_string());
             Ok(())
         })
     }
@@ -364,9 +812,14 @@ impl Menu {
     fn remove_at(&self, py: Python<'_>, position: usize) -> PyResult<Option<MenuItemKind>> {
         let item_kind = py.allow_threads(|| {
             let menu = self.0.inner_ref();
-            menu.remove_at(position)
+            let item_kind = menu
+                .remove_at(position)
                 .map_err(TauriError::from)
-                .map_err(PyErr::from)
+                .map_err(PyErr::from)?;
+            if let Some(item_kind) = &item_kind {
+                MenuItemHandlers::unset(menu.app_handle(), &item_kind.id().0);
+            }
+            PyResult::Ok(item_kind)
         })?;
 
         let item_kind = match item_kind {
@@ -428,6 +881,8 @@ impl Menu {
 }
 
 /// see also: [tauri::menu::Submenu]
+///
+/// See [Menu] for how to react to clicks on this submenu's items.
 #[pyclass(frozen)]
 #[non_exhaustive]
 pub struct Submenu(pub PyWrapper<PyWrapperT0<TauriSubmenu>>);
@@ -535,6 +990,36 @@ impl Submenu {
         ))?
     }
 
+    /// Build a whole [Submenu] tree from a nested Python structure in one call.
+    ///
+    /// `spec` is a dict with `text`, an optional `id`/`enabled`, and an `items` list, each
+    /// entry of which is a node as described by [MenuSpecNode]. See also: [SubmenuBuilder],
+    /// for building a submenu item-by-item instead.
+    #[staticmethod]
+    fn from_spec(py: Python<'_>, manager: ImplManager, spec: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let id: Option<String> = get_opt(spec, "id")?;
+        let text: String = get_req(spec, "text")?;
+        let enabled: bool = get_opt(spec, "enabled")?.unwrap_or(true);
+        let items: Vec<Bound<'_, PyAny>> = get_opt(spec, "items")?.unwrap_or_default();
+        let items = items
+            .iter()
+            .map(MenuSpecNode::parse)
+            .collect::<PyResult<Vec<_>>>()?;
+        let items = items
+            .iter()
+            .map(|item| item.build(py, &manager))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        manager_method_impl!(py, &manager, |py, manager| Self::new_impl(
+            py,
+            manager,
+            &text,
+            enabled,
+            id.clone().map(MenuId),
+            Some(items)
+        ))?
+    }
+
     fn app_handle(&self, py: Python<'_>) -> Py<ext_mod_impl::AppHandle> {
         let menu = self.0.inner_ref();
         // TODO, PERF: release the GIL?
@@ -547,6 +1032,43 @@ impl Submenu {
         MenuID::intern(py, &menu.id().0)
     }
 
+    /// Show this menu as a native context (right-click) menu on `window`.
+    ///
+    /// `window` may be either a [ext_mod_impl::window::Window] or a
+    /// [ext_mod_impl::webview::WebviewWindow].
+    ///
+    /// See also: [tauri::menu::ContextMenu::popup].
+    fn popup(&self, py: Python<'_>, window: ImplWindow) -> PyResult<()> {
+        py.allow_threads(|| {
+            let menu = self.0.inner_ref();
+            popup_window_impl!(&window, |window| {
+                menu.popup(window).map_err(TauriError::from)
+            })?;
+            Ok(())
+        })
+    }
+
+    /// Show this menu as a native context (right-click) menu on `window`, at `position`.
+    ///
+    /// `window` may be either a [ext_mod_impl::window::Window] or a
+    /// [ext_mod_impl::webview::WebviewWindow].
+    ///
+    /// See also: [tauri::menu::ContextMenu::popup_at].
+    fn popup_at(
+        &self,
+        py: Python<'_>,
+        window: ImplWindow,
+        position: ext_mod_impl::Position,
+    ) -> PyResult<()> {
+        py.allow_threads(|| {
+            let menu = self.0.inner_ref();
+            popup_window_impl!(&window, |window| {
+                menu.popup_at(window, position).map_err(TauriError::from)
+            })?;
+            Ok(())
+        })
+    }
+
     fn append(&self, py: Python<'_>, item: MenuItemKind) -> PyResult<()> {
         py.allow_threads(|| {
             let menu = self.0.inner_ref();
@@ -611,6 +1133,7 @@ impl Submenu {
             let menu = self.0.inner_ref();
             item.remove_from_menu(menu.deref())
                 .map_err(TauriError::from)?;
+            MenuItemHandlers::unset(menu.app_handle(), &item.id_string());
             Ok(())
         })
     }
@@ -618,9 +1141,14 @@ impl Submenu {
     fn remove_at(&self, py: Python<'_>, position: usize) -> PyResult<Option<MenuItemKind>> {
         let item_kind = py.allow_threads(|| {
             let menu = self.0.inner_ref();
-            menu.remove_at(position)
+            let item_kind = menu
+                .remove_at(position)
                 .map_err(TauriError::from)
-                .map_err(PyErr::from)
+                .map_err(PyErr::from)?;
+            if let Some(item_kind) = &item_kind {
+                MenuItemHandlers::unset(menu.app_handle(), &item_kind.id().0);
+            }
+            PyResult::Ok(item_kind)
         })?;
 
         let item_kind = match item_kind {
@@ -691,6 +1219,279 @@ impl Submenu {
     }
 }
 
+/// A fluent builder for [Menu], mirroring [tauri::menu::MenuBuilder].
+///
+/// Unlike [Menu::with_items], which requires a fully-materialized `list[MenuItemKind]`
+/// up front, this accumulates items in memory and creates the menu itself when
+/// [MenuBuilder::build] is called. The items are not deferred: [MenuBuilder::separator],
+/// [MenuBuilder::check_item], [MenuBuilder::icon_item] and [MenuBuilder::text] each create
+/// their item as soon as they are called, so `build` is not the only main-thread round trip.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct MenuBuilder {
+    manager: ImplManager,
+    id: Option<MenuId>,
+    items: PyWrapper<PyWrapperT1<Vec<MenuItemKind>>>,
+}
+
+impl MenuBuilder {
+    fn new(manager: ImplManager, id: Option<MenuId>) -> Self {
+        Self {
+            manager,
+            id,
+            items: PyWrapper::new1(Vec::new()),
+        }
+    }
+
+    fn push(&self, item: MenuItemKind) -> PyResult<()> {
+        self.items.lock_inner_mut()?.push(item);
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl MenuBuilder {
+    #[new]
+    fn __new__(manager: ImplManager) -> Self {
+        Self::new(manager, None)
+    }
+
+    #[staticmethod]
+    fn with_id(manager: ImplManager, id: String) -> Self {
+        Self::new(manager, Some(MenuId(id)))
+    }
+
+    fn item(slf: Py<Self>, item: MenuItemKind) -> PyResult<Py<Self>> {
+        slf.get().push(item)?;
+        Ok(slf)
+    }
+
+    fn items(slf: Py<Self>, items: Vec<MenuItemKind>) -> PyResult<Py<Self>> {
+        slf.get().items.lock_inner_mut()?.extend(items);
+        Ok(slf)
+    }
+
+    fn separator(slf: Py<Self>, py: Python<'_>) -> PyResult<Py<Self>> {
+        let item = manager_method_impl!(py, &slf.get().manager, |py, manager| {
+            PredefinedMenuItem::delegate_inner(py, manager, |manager| {
+                TauriPredefinedMenuItem::separator(manager)
+            })
+        })??;
+        slf.get().push(MenuItemKind::Predefined(
+            item.into_pyobject(py)?.unbind(),
+        ))?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (text, enabled, checked, accelerator=None, id=None))]
+    fn check_item(
+        slf: Py<Self>,
+        py: Python<'_>,
+        text: &str,
+        enabled: bool,
+        checked: bool,
+        accelerator: Option<&str>,
+        id: Option<String>,
+    ) -> PyResult<Py<Self>> {
+        let id = id.map(MenuId);
+        let item = manager_method_impl!(py, &slf.get().manager, |py, manager| {
+            CheckMenuItem::new_impl(py, manager, text, enabled, checked, accelerator, id, None)
+        })??;
+        slf.get()
+            .push(MenuItemKind::Check(item.into_pyobject(py)?.unbind()))?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (text, enabled, icon=None, accelerator=None, id=None))]
+    fn icon_item(
+        slf: Py<Self>,
+        py: Python<'_>,
+        text: &str,
+        enabled: bool,
+        icon: Option<Py<ext_mod_impl::image::Image>>,
+        accelerator: Option<&str>,
+        id: Option<String>,
+    ) -> PyResult<Py<Self>> {
+        let icon = IconOrNative::Icon(icon.as_ref().map(|icon| icon.get().to_tauri(py)));
+        let id = id.map(MenuId);
+        let item = manager_method_impl!(py, &slf.get().manager, |py, manager| {
+            IconMenuItem::new_impl(py, manager, text, enabled, icon, accelerator, id, None)
+        })??;
+        slf.get()
+            .push(MenuItemKind::Icon(item.into_pyobject(py)?.unbind()))?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (text, enabled, accelerator=None, id=None))]
+    fn text(
+        slf: Py<Self>,
+        py: Python<'_>,
+        text: &str,
+        enabled: bool,
+        accelerator: Option<&str>,
+        id: Option<String>,
+    ) -> PyResult<Py<Self>> {
+        let id = id.map(MenuId);
+        let item = manager_method_impl!(py, &slf.get().manager, |py, manager| {
+            MenuItem::new_impl(py, manager, text, enabled, accelerator, id, None)
+        })??;
+        slf.get()
+            .push(MenuItemKind::MenuItem(item.into_pyobject(py)?.unbind()))?;
+        Ok(slf)
+    }
+
+    /// Consume the builder, constructing the [Menu] from the accumulated items.
+    ///
+    /// The builder is left empty afterwards; calling `build` again produces an
+    /// (otherwise identical) empty-item menu, matching `Menu.with_id_and_items([])`.
+    fn build(&self, py: Python<'_>) -> PyResult<Menu> {
+        let items = std::mem::take(&mut *self.items.lock_inner_mut()?);
+        let id = self.id.clone();
+        manager_method_impl!(py, &self.manager, |py, manager| Menu::new_impl(
+            py,
+            manager,
+            id,
+            Some(items)
+        ))?
+    }
+}
+
+/// A fluent builder for [Submenu], mirroring [tauri::menu::SubmenuBuilder].
+///
+/// See [MenuBuilder] for why this exists instead of repeated [Submenu::append] calls.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct SubmenuBuilder {
+    manager: ImplManager,
+    text: String,
+    enabled: bool,
+    id: Option<MenuId>,
+    items: PyWrapper<PyWrapperT1<Vec<MenuItemKind>>>,
+}
+
+impl SubmenuBuilder {
+    fn new(manager: ImplManager, text: String, enabled: bool, id: Option<MenuId>) -> Self {
+        Self {
+            manager,
+            text,
+            enabled,
+            id,
+            items: PyWrapper::new1(Vec::new()),
+        }
+    }
+
+    fn push(&self, item: MenuItemKind) -> PyResult<()> {
+        self.items.lock_inner_mut()?.push(item);
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl SubmenuBuilder {
+    #[new]
+    fn __new__(manager: ImplManager, text: String, enabled: bool) -> Self {
+        Self::new(manager, text, enabled, None)
+    }
+
+    #[staticmethod]
+    fn with_id(manager: ImplManager, id: String, text: String, enabled: bool) -> Self {
+        Self::new(manager, text, enabled, Some(MenuId(id)))
+    }
+
+    fn item(slf: Py<Self>, item: MenuItemKind) -> PyResult<Py<Self>> {
+        slf.get().push(item)?;
+        Ok(slf)
+    }
+
+    fn items(slf: Py<Self>, items: Vec<MenuItemKind>) -> PyResult<Py<Self>> {
+        slf.get().items.lock_inner_mut()?.extend(items);
+        Ok(slf)
+    }
+
+    fn separator(slf: Py<Self>, py: Python<'_>) -> PyResult<Py<Self>> {
+        let item = manager_method_impl!(py, &slf.get().manager, |py, manager| {
+            PredefinedMenuItem::delegate_inner(py, manager, |manager| {
+                TauriPredefinedMenuItem::separator(manager)
+            })
+        })??;
+        slf.get().push(MenuItemKind::Predefined(
+            item.into_pyobject(py)?.unbind(),
+        ))?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (text, enabled, checked, accelerator=None, id=None))]
+    fn check_item(
+        slf: Py<Self>,
+        py: Python<'_>,
+        text: &str,
+        enabled: bool,
+        checked: bool,
+        accelerator: Option<&str>,
+        id: Option<String>,
+    ) -> PyResult<Py<Self>> {
+        let id = id.map(MenuId);
+        let item = manager_method_impl!(py, &slf.get().manager, |py, manager| {
+            CheckMenuItem::new_impl(py, manager, text, enabled, checked, accelerator, id, None)
+        })??;
+        slf.get()
+            .push(MenuItemKind::Check(item.into_pyobject(py)?.unbind()))?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (text, enabled, icon=None, accelerator=None, id=None))]
+    fn icon_item(
+        slf: Py<Self>,
+        py: Python<'_>,
+        text: &str,
+        enabled: bool,
+        icon: Option<Py<ext_mod_impl::image::Image>>,
+        accelerator: Option<&str>,
+        id: Option<String>,
+    ) -> PyResult<Py<Self>> {
+        let icon = IconOrNative::Icon(icon.as_ref().map(|icon| icon.get().to_tauri(py)));
+        let id = id.map(MenuId);
+        let item = manager_method_impl!(py, &slf.get().manager, |py, manager| {
+            IconMenuItem::new_impl(py, manager, text, enabled, icon, accelerator, id, None)
+        })??;
+        slf.get()
+            .push(MenuItemKind::Icon(item.into_pyobject(py)?.unbind()))?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (text, enabled, accelerator=None, id=None))]
+    fn text(
+        slf: Py<Self>,
+        py: Python<'_>,
+        text: &str,
+        enabled: bool,
+        accelerator: Option<&str>,
+        id: Option<String>,
+    ) -> PyResult<Py<Self>> {
+        let id = id.map(MenuId);
+        let item = manager_method_impl!(py, &slf.get().manager, |py, manager| {
+            MenuItem::new_impl(py, manager, text, enabled, accelerator, id, None)
+        })??;
+        slf.get()
+            .push(MenuItemKind::MenuItem(item.into_pyobject(py)?.unbind()))?;
+        Ok(slf)
+    }
+
+    /// Consume the builder, constructing the [Submenu] from the accumulated items.
+    fn build(&self, py: Python<'_>) -> PyResult<Submenu> {
+        let items = std::mem::take(&mut *self.items.lock_inner_mut()?);
+        let id = self.id.clone();
+        manager_method_impl!(py, &self.manager, |py, manager| Submenu::new_impl(
+            py,
+            manager,
+            &self.text,
+            self.enabled,
+            id,
+            Some(items)
+        ))?
+    }
+}
+
 /// see also: [tauri::menu::MenuItem]
 #[pyclass(frozen)]
 #[non_exhaustive]
@@ -709,6 +1510,7 @@ impl MenuItem {
         enabled: bool,
         accelerator: Option<&str>,
         id: Option<impl Into<menu::MenuId> + Send>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         unsafe {
             py.allow_threads_unsend(manager, |manager| {
@@ -718,6 +1520,10 @@ impl MenuItem {
                     TauriMenuItem::new(manager, text, enabled, accelerator)
                 }?;
 
+                if let Some(handler) = handler {
+                    MenuItemHandlers::set(manager, menu.id(), handler);
+                }
+
                 tauri::Result::Ok(Self::new(menu))
             })
         }
@@ -729,13 +1535,14 @@ impl MenuItem {
 #[pymethods]
 impl MenuItem {
     #[new]
-    #[pyo3(signature = (manager, text, enabled, accelerator=None))]
+    #[pyo3(signature = (manager, text, enabled, accelerator=None, handler=None))]
     fn __new__(
         py: Python<'_>,
         manager: ImplManager,
         text: &str,
         enabled: bool,
         accelerator: Option<&str>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         manager_method_impl!(py, &manager, |py, manager| Self::new_impl(
             py,
@@ -743,12 +1550,13 @@ impl MenuItem {
             text,
             enabled,
             accelerator,
-            None::<&str>
+            None::<&str>,
+            handler
         ))?
     }
 
     #[staticmethod]
-    #[pyo3(signature = (manager, id, text, enabled, accelerator=None))]
+    #[pyo3(signature = (manager, id, text, enabled, accelerator=None, handler=None))]
     fn with_id(
         py: Python<'_>,
         manager: ImplManager,
@@ -756,6 +1564,7 @@ impl MenuItem {
         text: &str,
         enabled: bool,
         accelerator: Option<&str>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         manager_method_impl!(py, &manager, |py, manager| Self::new_impl(
             py,
@@ -764,6 +1573,7 @@ impl MenuItem {
             enabled,
             accelerator,
             Some(MenuId(id)),
+            handler
         ))?
     }
 
@@ -779,6 +1589,14 @@ impl MenuItem {
         MenuID::intern(py, &menu.id().0)
     }
 
+    /// Register `handler` to be called with `(AppHandle, MenuEvent)` whenever this
+    /// specific item is activated, instead of only reaching it through the app-wide
+    /// [ext_mod_impl::AppHandle::on_menu_event] dispatcher.
+    fn set_handler(&self, handler: Py<PyAny>) {
+        let menu = self.0.inner_ref();
+        MenuItemHandlers::set(menu.app_handle(), menu.id(), handler);
+    }
+
     fn text(&self, py: Python<'_>) -> PyResult<String> {
         py.allow_threads(|| {
             let menu = self.0.inner_ref();
@@ -803,22 +1621,113 @@ impl MenuItem {
         })
     }
 
-    fn set_enabled(&self, py: Python<'_>, enabled: bool) -> PyResult<()> {
-        py.allow_threads(|| {
-            let menu = self.0.inner_ref();
-            menu.set_enabled(enabled).map_err(TauriError::from)?;
-            Ok(())
-        })
+    fn set_enabled(&self, py: Python<'_>, enabled: bool) -> PyResult<()> {
+        py.allow_threads(|| {
+            let menu = self.0.inner_ref();
+            menu.set_enabled(enabled).map_err(TauriError::from)?;
+            Ok(())
+        })
+    }
+
+    #[pyo3(signature = (accelerator))]
+    fn set_accelerator(&self, py: Python<'_>, accelerator: Option<&str>) -> PyResult<()> {
+        py.allow_threads(|| {
+            let menu = self.0.inner_ref();
+            menu.set_accelerator(accelerator)
+                .map_err(TauriError::from)?;
+            Ok(())
+        })
+    }
+}
+
+struct MenuItemBuilderState {
+    text: String,
+    enabled: bool,
+    accelerator: Option<String>,
+    id: Option<MenuId>,
+    handler: Option<Py<PyAny>>,
+}
+
+/// A fluent builder for [MenuItem], mirroring [tauri::menu::MenuItemBuilder].
+///
+/// See [CheckMenuItemBuilder] for the rationale (every field optional until [Self::build]).
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct MenuItemBuilder {
+    manager: ImplManager,
+    state: PyWrapper<PyWrapperT1<MenuItemBuilderState>>,
+}
+
+impl MenuItemBuilder {
+    fn new(manager: ImplManager) -> Self {
+        Self {
+            manager,
+            state: PyWrapper::new1(MenuItemBuilderState {
+                text: String::new(),
+                enabled: true,
+                accelerator: None,
+                id: None,
+                handler: None,
+            }),
+        }
+    }
+}
+
+#[pymethods]
+impl MenuItemBuilder {
+    #[new]
+    fn __new__(manager: ImplManager) -> Self {
+        Self::new(manager)
+    }
+
+    fn text(slf: Py<Self>, text: String) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.text = text;
+        Ok(slf)
+    }
+
+    fn enabled(slf: Py<Self>, enabled: bool) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.enabled = enabled;
+        Ok(slf)
+    }
+
+    fn accelerator(slf: Py<Self>, accelerator: Option<String>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.accelerator = accelerator;
+        Ok(slf)
     }
 
-    #[pyo3(signature = (accelerator))]
-    fn set_accelerator(&self, py: Python<'_>, accelerator: Option<&str>) -> PyResult<()> {
-        py.allow_threads(|| {
-            let menu = self.0.inner_ref();
-            menu.set_accelerator(accelerator)
-                .map_err(TauriError::from)?;
-            Ok(())
-        })
+    fn id(slf: Py<Self>, id: String) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.id = Some(MenuId(id));
+        Ok(slf)
+    }
+
+    fn handler(slf: Py<Self>, handler: Py<PyAny>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.handler = Some(handler);
+        Ok(slf)
+    }
+
+    /// Construct the [MenuItem] from the accumulated fields. The builder keeps its fields, so
+    /// `build` can be called again.
+    fn build(&self, py: Python<'_>) -> PyResult<MenuItem> {
+        let (text, enabled, accelerator, id, handler) = {
+            let state = self.state.lock_inner_ref()?;
+            (
+                state.text.clone(),
+                state.enabled,
+                state.accelerator.clone(),
+                state.id.clone(),
+                state.handler.as_ref().map(|handler| handler.clone_ref(py)),
+            )
+        };
+
+        manager_method_impl!(py, &self.manager, |py, manager| MenuItem::new_impl(
+            py,
+            manager,
+            &text,
+            enabled,
+            accelerator.as_deref(),
+            id,
+            handler
+        ))?
     }
 }
 
@@ -1078,27 +1987,50 @@ impl CheckMenuItem {
         checked: bool,
         accelerator: Option<&str>,
         id: Option<impl Into<menu::MenuId> + Send>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
-        unsafe {
+        let item = unsafe {
             py.allow_threads_unsend(manager, |manager| {
                 let menu = if let Some(id) = id {
                     TauriCheckMenuItem::with_id(manager, id, text, enabled, checked, accelerator)
                 } else {
                     TauriCheckMenuItem::new(manager, text, enabled, checked, accelerator)
                 }?;
-
                 tauri::Result::Ok(Self::new(menu))
             })
         }
         .map_err(TauriError::from)
-        .map_err(PyErr::from)
+        .map_err(PyErr::from)?;
+
+        if let Some(handler) = handler {
+            item.register_handler(py, manager, handler)?;
+        }
+
+        Ok(item)
+    }
+
+    /// Register `handler` in [MenuItemHandlers], keyed by this item's id, along with a
+    /// fresh Python wrapper so [MenuItemHandlers::dispatch] can read the checked state at
+    /// fire time. Requires the GIL (to create that wrapper), unlike [MenuItem]/[IconMenuItem]
+    /// whose plain handlers can be registered from inside the GIL-released constructor.
+    fn register_handler(
+        &self,
+        py: Python<'_>,
+        manager: &impl tauri::Manager<Runtime>,
+        handler: Py<PyAny>,
+    ) -> PyResult<()> {
+        let menu = self.0.inner_ref();
+        let id = menu.id().clone();
+        let item = Self::new(menu.to_owned()).into_pyobject(py)?.unbind();
+        MenuItemHandlers::set_check(manager, &id, handler, item);
+        Ok(())
     }
 }
 
 #[pymethods]
 impl CheckMenuItem {
     #[new]
-    #[pyo3(signature = (manager, text, enabled, checked, accelerator=None))]
+    #[pyo3(signature = (manager, text, enabled, checked, accelerator=None, handler=None))]
     fn __new__(
         py: Python<'_>,
         manager: ImplManager,
@@ -1106,6 +2038,7 @@ impl CheckMenuItem {
         enabled: bool,
         checked: bool,
         accelerator: Option<&str>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         manager_method_impl!(py, &manager, |py, manager| Self::new_impl(
             py,
@@ -1115,11 +2048,12 @@ impl CheckMenuItem {
             checked,
             accelerator,
             None::<&str>,
+            handler
         ))?
     }
 
     #[staticmethod]
-    #[pyo3(signature = (manager, id, text, enabled, checked, accelerator=None))]
+    #[pyo3(signature = (manager, id, text, enabled, checked, accelerator=None, handler=None))]
     fn with_id(
         py: Python<'_>,
         manager: ImplManager,
@@ -1128,6 +2062,7 @@ impl CheckMenuItem {
         enabled: bool,
         checked: bool,
         accelerator: Option<&str>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         manager_method_impl!(py, &manager, |py, manager| Self::new_impl(
             py,
@@ -1137,6 +2072,7 @@ impl CheckMenuItem {
             checked,
             accelerator,
             Some(MenuId(id)),
+            handler
         ))?
     }
 
@@ -1152,6 +2088,15 @@ impl CheckMenuItem {
         MenuID::intern(py, &menu.id().0)
     }
 
+    /// Register `handler` to be called with `(AppHandle, MenuEvent, checked)` whenever this
+    /// specific item is activated, instead of only reaching it through the app-wide
+    /// [ext_mod_impl::AppHandle::on_menu_event] dispatcher. `checked` is this item's state
+    /// *after* the click that fired the event.
+    fn set_handler(&self, py: Python<'_>, handler: Py<PyAny>) -> PyResult<()> {
+        let app_handle = self.0.inner_ref().app_handle().clone();
+        self.register_handler(py, &app_handle, handler)
+    }
+
     fn text(&self, py: Python<'_>) -> PyResult<String> {
         py.allow_threads(|| {
             let menu = self.0.inner_ref();
@@ -1209,6 +2154,226 @@ impl CheckMenuItem {
             Ok(())
         })
     }
+
+    /// Start a batched-mutation transaction on this item.
+    ///
+    /// Each `set_*`/`get_*` round trip on [Self] releases and reacquires the GIL on its own;
+    /// updating several fields at once therefore costs one `allow_threads` per field. Use this
+    /// as a `with` block instead to queue the mutations and flush them all inside a single
+    /// `allow_threads` region on exit: `with item.update() as u: u.set_text(...).set_checked(...)`.
+    fn update(&self) -> CheckMenuItemUpdate {
+        CheckMenuItemUpdate::new(self.0.inner_ref().to_owned())
+    }
+}
+
+enum CheckMenuItemOp {
+    Text(String),
+    Enabled(bool),
+    Accelerator(Option<String>),
+    Checked(bool),
+}
+
+struct CheckMenuItemUpdateState {
+    ops: Vec<CheckMenuItemOp>,
+}
+
+/// A batched-mutation transaction for [CheckMenuItem], returned by [CheckMenuItem::update].
+///
+/// Queued mutations are applied in the order they were recorded, inside a single
+/// `allow_threads` region, when the `with` block exits. The first error encountered aborts
+/// the remaining queued mutations and is raised from `__exit__`.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct CheckMenuItemUpdate {
+    item: TauriCheckMenuItem,
+    state: PyWrapper<PyWrapperT1<CheckMenuItemUpdateState>>,
+}
+
+impl CheckMenuItemUpdate {
+    fn new(item: TauriCheckMenuItem) -> Self {
+        Self {
+            item,
+            state: PyWrapper::new1(CheckMenuItemUpdateState { ops: Vec::new() }),
+        }
+    }
+}
+
+#[pymethods]
+impl CheckMenuItemUpdate {
+    fn set_text(slf: Py<Self>, text: String) -> PyResult<Py<Self>> {
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(CheckMenuItemOp::Text(text));
+        Ok(slf)
+    }
+
+    fn set_enabled(slf: Py<Self>, enabled: bool) -> PyResult<Py<Self>> {
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(CheckMenuItemOp::Enabled(enabled));
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (accelerator))]
+    fn set_accelerator(slf: Py<Self>, accelerator: Option<String>) -> PyResult<Py<Self>> {
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(CheckMenuItemOp::Accelerator(accelerator));
+        Ok(slf)
+    }
+
+    fn set_checked(slf: Py<Self>, checked: bool) -> PyResult<Py<Self>> {
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(CheckMenuItemOp::Checked(checked));
+        Ok(slf)
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    #[pyo3(signature = (_exc_type, _exc_value, _traceback))]
+    fn __exit__(
+        &self,
+        py: Python<'_>,
+        _exc_type: Option<Py<PyAny>>,
+        _exc_value: Option<Py<PyAny>>,
+        _traceback: Option<Py<PyAny>>,
+    ) -> PyResult<bool> {
+        let ops = std::mem::take(&mut self.state.lock_inner_mut()?.ops);
+        let menu = &self.item;
+        py.allow_threads(|| {
+            for op in ops {
+                match op {
+                    CheckMenuItemOp::Text(text) => {
+                        menu.set_text(&text).map_err(TauriError::from)?
+                    }
+                    CheckMenuItemOp::Enabled(enabled) => {
+                        menu.set_enabled(enabled).map_err(TauriError::from)?
+                    }
+                    CheckMenuItemOp::Accelerator(accelerator) => menu
+                        .set_accelerator(accelerator.as_deref())
+                        .map_err(TauriError::from)?,
+                    CheckMenuItemOp::Checked(checked) => {
+                        menu.set_checked(checked).map_err(TauriError::from)?
+                    }
+                }
+            }
+            Ok(())
+        })?;
+        Ok(false)
+    }
+}
+
+struct CheckMenuItemBuilderState {
+    text: String,
+    enabled: bool,
+    checked: bool,
+    accelerator: Option<String>,
+    id: Option<MenuId>,
+    handler: Option<Py<PyAny>>,
+}
+
+/// A fluent builder for [CheckMenuItem], mirroring [tauri::menu::CheckMenuItemBuilder].
+///
+/// Unlike [CheckMenuItem::__new__]/[CheckMenuItem::with_id], every field here is optional
+/// until [Self::build] is called, avoiding the need to pick a placeholder `text`/`checked`
+/// up front just to set an `accelerator` or `handler`.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct CheckMenuItemBuilder {
+    manager: ImplManager,
+    state: PyWrapper<PyWrapperT1<CheckMenuItemBuilderState>>,
+}
+
+impl CheckMenuItemBuilder {
+    fn new(manager: ImplManager) -> Self {
+        Self {
+            manager,
+            state: PyWrapper::new1(CheckMenuItemBuilderState {
+                text: String::new(),
+                enabled: true,
+                checked: false,
+                accelerator: None,
+                id: None,
+                handler: None,
+            }),
+        }
+    }
+}
+
+#[pymethods]
+impl CheckMenuItemBuilder {
+    #[new]
+    fn __new__(manager: ImplManager) -> Self {
+        Self::new(manager)
+    }
+
+    fn text(slf: Py<Self>, text: String) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.text = text;
+        Ok(slf)
+    }
+
+    fn enabled(slf: Py<Self>, enabled: bool) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.enabled = enabled;
+        Ok(slf)
+    }
+
+    fn checked(slf: Py<Self>, checked: bool) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.checked = checked;
+        Ok(slf)
+    }
+
+    fn accelerator(slf: Py<Self>, accelerator: Option<String>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.accelerator = accelerator;
+        Ok(slf)
+    }
+
+    fn id(slf: Py<Self>, id: String) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.id = Some(MenuId(id));
+        Ok(slf)
+    }
+
+    fn handler(slf: Py<Self>, handler: Py<PyAny>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.handler = Some(handler);
+        Ok(slf)
+    }
+
+    /// Construct the [CheckMenuItem] from the accumulated fields. The builder keeps its
+    /// fields, so `build` can be called again.
+    fn build(&self, py: Python<'_>) -> PyResult<CheckMenuItem> {
+        let (text, enabled, checked, accelerator, id, handler) = {
+            let state = self.state.lock_inner_ref()?;
+            (
+                state.text.clone(),
+                state.enabled,
+                state.checked,
+                state.accelerator.clone(),
+                state.id.clone(),
+                state.handler.as_ref().map(|handler| handler.clone_ref(py)),
+            )
+        };
+
+        manager_method_impl!(py, &self.manager, |py, manager| CheckMenuItem::new_impl(
+            py,
+            manager,
+            &text,
+            enabled,
+            checked,
+            accelerator.as_deref(),
+            id,
+            handler
+        ))?
+    }
 }
 
 trait PyStrToRs {
@@ -1338,6 +2503,15 @@ enum IconOrNative<'a> {
     Native(Option<menu::NativeIcon>),
 }
 
+/// See [NativeIcon::is_supported]: upstream Tauri only implements native icons on macOS, so
+/// setting one elsewhere is a user-visible mistake rather than a silent no-op.
+fn native_icon_unsupported_err() -> PyErr {
+    PyNotImplementedError::new_err(
+        "`NativeIcon` is only supported on macOS; call `NativeIcon.is_supported()` to check \
+         before setting one",
+    )
+}
+
 /// see also: [tauri::menu::IconMenuItem]
 #[pyclass(frozen)]
 #[non_exhaustive]
@@ -1357,7 +2531,12 @@ impl IconMenuItem {
         icon_or_native: IconOrNative<'_>,
         accelerator: Option<&str>,
         id: Option<impl Into<menu::MenuId> + Send>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
+        if matches!(icon_or_native, IconOrNative::Native(Some(_))) && !NativeIcon::is_supported() {
+            return Err(native_icon_unsupported_err());
+        }
+
         unsafe {
             py.allow_threads_unsend(manager, |manager| {
                 let menu = if let Some(id) = id {
@@ -1396,6 +2575,10 @@ impl IconMenuItem {
                     }
                 }?;
 
+                if let Some(handler) = handler {
+                    MenuItemHandlers::set(manager, menu.id(), handler);
+                }
+
                 tauri::Result::Ok(Self::new(menu))
             })
         }
@@ -1407,7 +2590,7 @@ impl IconMenuItem {
 #[pymethods]
 impl IconMenuItem {
     #[new]
-    #[pyo3(signature = (manager, text, enabled, icon=None, accelerator=None))]
+    #[pyo3(signature = (manager, text, enabled, icon=None, accelerator=None, handler=None))]
     fn __new__(
         py: Python<'_>,
         manager: ImplManager,
@@ -1415,6 +2598,7 @@ impl IconMenuItem {
         enabled: bool,
         icon: Option<Py<ext_mod_impl::image::Image>>,
         accelerator: Option<&str>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let icon = icon.as_ref().map(|icon| icon.get().to_tauri(py));
         let icon = IconOrNative::Icon(icon);
@@ -1427,11 +2611,12 @@ impl IconMenuItem {
             icon,
             accelerator,
             None::<&str>,
+            handler
         ))?
     }
 
     #[staticmethod]
-    #[pyo3(signature = (manager, id, text, enabled, icon=None, accelerator=None))]
+    #[pyo3(signature = (manager, id, text, enabled, icon=None, accelerator=None, handler=None))]
     fn with_id(
         py: Python<'_>,
         manager: ImplManager,
@@ -1440,6 +2625,7 @@ impl IconMenuItem {
         enabled: bool,
         icon: Option<Py<ext_mod_impl::image::Image>>,
         accelerator: Option<&str>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let icon = icon.as_ref().map(|icon| icon.get().to_tauri(py));
         let icon = IconOrNative::Icon(icon);
@@ -1452,11 +2638,12 @@ impl IconMenuItem {
             icon,
             accelerator,
             Some(MenuId(id)),
+            handler
         ))?
     }
 
     #[staticmethod]
-    #[pyo3(signature = (manager, text, enabled, native_icon=None, accelerator=None))]
+    #[pyo3(signature = (manager, text, enabled, native_icon=None, accelerator=None, handler=None))]
     fn with_native_icon(
         py: Python<'_>,
         manager: ImplManager,
@@ -1464,6 +2651,7 @@ impl IconMenuItem {
         enabled: bool,
         native_icon: Option<NativeIcon>,
         accelerator: Option<&str>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let native_icon = native_icon.map(|native_icon| native_icon.into());
         let native_icon = IconOrNative::Native(native_icon);
@@ -1476,11 +2664,12 @@ impl IconMenuItem {
             native_icon,
             accelerator,
             None::<&str>,
+            handler
         ))?
     }
 
     #[staticmethod]
-    #[pyo3(signature = (manager, id, text, enabled, native_icon=None, accelerator=None))]
+    #[pyo3(signature = (manager, id, text, enabled, native_icon=None, accelerator=None, handler=None))]
     fn with_id_and_native_icon(
         py: Python<'_>,
         manager: ImplManager,
@@ -1489,6 +2678,7 @@ impl IconMenuItem {
         enabled: bool,
         native_icon: Option<NativeIcon>,
         accelerator: Option<&str>,
+        handler: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let native_icon = native_icon.map(|native_icon| native_icon.into());
         let native_icon = IconOrNative::Native(native_icon);
@@ -1501,6 +2691,7 @@ impl IconMenuItem {
             native_icon,
             accelerator,
             Some(MenuId(id)),
+            handler
         ))?
     }
 
@@ -1516,6 +2707,11 @@ impl IconMenuItem {
         MenuID::intern(py, &menu.id().0)
     }
 
+    fn set_handler(&self, handler: Py<PyAny>) {
+        let menu = self.0.inner_ref();
+        MenuItemHandlers::set(menu.app_handle(), menu.id(), handler);
+    }
+
     fn text(&self, py: Python<'_>) -> PyResult<String> {
         py.allow_threads(|| {
             let menu = self.0.inner_ref();
@@ -1574,6 +2770,9 @@ impl IconMenuItem {
 
     #[pyo3(signature = (native_icon))]
     fn set_native_icon(&self, py: Python<'_>, native_icon: Option<NativeIcon>) -> PyResult<()> {
+        if native_icon.is_some() && !NativeIcon::is_supported() {
+            return Err(native_icon_unsupported_err());
+        }
         let native_icon = native_icon.map(|native_icon| native_icon.into());
         py.allow_threads(|| {
             let menu = self.0.inner_ref();
@@ -1582,6 +2781,288 @@ impl IconMenuItem {
             Ok(())
         })
     }
+
+    /// Start a batched-mutation transaction on this item, see [CheckMenuItem::update].
+    fn update(&self) -> IconMenuItemUpdate {
+        IconMenuItemUpdate::new(self.0.inner_ref().to_owned())
+    }
+}
+
+enum IconMenuItemOp {
+    Text(String),
+    Enabled(bool),
+    Accelerator(Option<String>),
+    Icon(Option<Py<ext_mod_impl::image::Image>>),
+    NativeIcon(Option<NativeIcon>),
+}
+
+struct IconMenuItemUpdateState {
+    ops: Vec<IconMenuItemOp>,
+}
+
+/// A batched-mutation transaction for [IconMenuItem], returned by [IconMenuItem::update].
+///
+/// See [CheckMenuItemUpdate] for the semantics shared by both transaction types.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct IconMenuItemUpdate {
+    item: TauriIconMenuItem,
+    state: PyWrapper<PyWrapperT1<IconMenuItemUpdateState>>,
+}
+
+impl IconMenuItemUpdate {
+    fn new(item: TauriIconMenuItem) -> Self {
+        Self {
+            item,
+            state: PyWrapper::new1(IconMenuItemUpdateState { ops: Vec::new() }),
+        }
+    }
+}
+
+#[pymethods]
+impl IconMenuItemUpdate {
+    fn set_text(slf: Py<Self>, text: String) -> PyResult<Py<Self>> {
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(IconMenuItemOp::Text(text));
+        Ok(slf)
+    }
+
+    fn set_enabled(slf: Py<Self>, enabled: bool) -> PyResult<Py<Self>> {
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(IconMenuItemOp::Enabled(enabled));
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (accelerator))]
+    fn set_accelerator(slf: Py<Self>, accelerator: Option<String>) -> PyResult<Py<Self>> {
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(IconMenuItemOp::Accelerator(accelerator));
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (icon))]
+    fn set_icon(
+        slf: Py<Self>,
+        icon: Option<Py<ext_mod_impl::image::Image>>,
+    ) -> PyResult<Py<Self>> {
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(IconMenuItemOp::Icon(icon));
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (native_icon))]
+    fn set_native_icon(slf: Py<Self>, native_icon: Option<NativeIcon>) -> PyResult<Py<Self>> {
+        if native_icon.is_some() && !NativeIcon::is_supported() {
+            return Err(native_icon_unsupported_err());
+        }
+        slf.get()
+            .state
+            .lock_inner_mut()?
+            .ops
+            .push(IconMenuItemOp::NativeIcon(native_icon));
+        Ok(slf)
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    #[pyo3(signature = (_exc_type, _exc_value, _traceback))]
+    fn __exit__(
+        &self,
+        py: Python<'_>,
+        _exc_type: Option<Py<PyAny>>,
+        _exc_value: Option<Py<PyAny>>,
+        _traceback: Option<Py<PyAny>>,
+    ) -> PyResult<bool> {
+        let ops = std::mem::take(&mut self.state.lock_inner_mut()?.ops);
+
+        // `Image::to_tauri` borrows from `&Py<Image>`, not from `py` (see
+        // [ext_mod_impl::image::Image::to_tauri]), so these must be resolved here, while the
+        // owning `ops` entries are still alive, and carried alongside `ops` into `allow_threads`.
+        let images: Vec<Option<tauri::image::Image<'_>>> = ops
+            .iter()
+            .map(|op| match op {
+                IconMenuItemOp::Icon(icon) => icon.as_ref().map(|icon| icon.get().to_tauri(py)),
+                _ => None,
+            })
+            .collect();
+
+        let menu = &self.item;
+        py.allow_threads(|| {
+            for (op, image) in ops.iter().zip(images) {
+                match op {
+                    IconMenuItemOp::Text(text) => {
+                        menu.set_text(text.as_str()).map_err(TauriError::from)?
+                    }
+                    IconMenuItemOp::Enabled(enabled) => {
+                        menu.set_enabled(*enabled).map_err(TauriError::from)?
+                    }
+                    IconMenuItemOp::Accelerator(accelerator) => menu
+                        .set_accelerator(accelerator.as_deref())
+                        .map_err(TauriError::from)?,
+                    IconMenuItemOp::Icon(_) => {
+                        menu.set_icon(image).map_err(TauriError::from)?
+                    }
+                    IconMenuItemOp::NativeIcon(native_icon) => menu
+                        .set_native_icon((*native_icon).map(Into::into))
+                        .map_err(TauriError::from)?,
+                }
+            }
+            Ok(())
+        })?;
+        Ok(false)
+    }
+}
+
+enum IconMenuItemBuilderIcon {
+    None,
+    Image(Py<ext_mod_impl::image::Image>),
+    Native(NativeIcon),
+}
+
+impl IconMenuItemBuilderIcon {
+    fn clone_ref(&self, py: Python<'_>) -> Self {
+        match self {
+            Self::None => Self::None,
+            Self::Image(image) => Self::Image(image.clone_ref(py)),
+            Self::Native(native_icon) => Self::Native(*native_icon),
+        }
+    }
+
+    /// Borrows from `&self`, not from `py` — keep `self` alive for as long as the
+    /// returned value is used (see [ext_mod_impl::image::Image::to_tauri]).
+    fn as_icon_or_native(&self, py: Python<'_>) -> IconOrNative<'_> {
+        match self {
+            Self::None => IconOrNative::Icon(None),
+            Self::Image(image) => IconOrNative::Icon(Some(image.get().to_tauri(py))),
+            Self::Native(native_icon) => IconOrNative::Native(Some((*native_icon).into())),
+        }
+    }
+}
+
+struct IconMenuItemBuilderState {
+    text: String,
+    enabled: bool,
+    icon: IconMenuItemBuilderIcon,
+    accelerator: Option<String>,
+    id: Option<MenuId>,
+    handler: Option<Py<PyAny>>,
+}
+
+/// A fluent builder for [IconMenuItem], mirroring [tauri::menu::IconMenuItemBuilder].
+///
+/// Replaces the `with_id`/`with_native_icon`/`with_id_and_native_icon` static-method
+/// combinatorics on [IconMenuItem] with incremental `.icon(...)`/`.native_icon(...)` calls;
+/// whichever is called last wins, matching [tauri::menu::IconMenuItemBuilder::icon] and
+/// [tauri::menu::IconMenuItemBuilder::native_icon].
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct IconMenuItemBuilder {
+    manager: ImplManager,
+    state: PyWrapper<PyWrapperT1<IconMenuItemBuilderState>>,
+}
+
+impl IconMenuItemBuilder {
+    fn new(manager: ImplManager) -> Self {
+        Self {
+            manager,
+            state: PyWrapper::new1(IconMenuItemBuilderState {
+                text: String::new(),
+                enabled: true,
+                icon: IconMenuItemBuilderIcon::None,
+                accelerator: None,
+                id: None,
+                handler: None,
+            }),
+        }
+    }
+}
+
+#[pymethods]
+impl IconMenuItemBuilder {
+    #[new]
+    fn __new__(manager: ImplManager) -> Self {
+        Self::new(manager)
+    }
+
+    fn text(slf: Py<Self>, text: String) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.text = text;
+        Ok(slf)
+    }
+
+    fn enabled(slf: Py<Self>, enabled: bool) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.enabled = enabled;
+        Ok(slf)
+    }
+
+    fn icon(slf: Py<Self>, icon: Py<ext_mod_impl::image::Image>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.icon = IconMenuItemBuilderIcon::Image(icon);
+        Ok(slf)
+    }
+
+    fn native_icon(slf: Py<Self>, native_icon: NativeIcon) -> PyResult<Py<Self>> {
+        if !NativeIcon::is_supported() {
+            return Err(native_icon_unsupported_err());
+        }
+        slf.get().state.lock_inner_mut()?.icon = IconMenuItemBuilderIcon::Native(native_icon);
+        Ok(slf)
+    }
+
+    fn accelerator(slf: Py<Self>, accelerator: Option<String>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.accelerator = accelerator;
+        Ok(slf)
+    }
+
+    fn id(slf: Py<Self>, id: String) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.id = Some(MenuId(id));
+        Ok(slf)
+    }
+
+    fn handler(slf: Py<Self>, handler: Py<PyAny>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.handler = Some(handler);
+        Ok(slf)
+    }
+
+    /// Construct the [IconMenuItem] from the accumulated fields. The builder keeps its
+    /// fields, so `build` can be called again.
+    fn build(&self, py: Python<'_>) -> PyResult<IconMenuItem> {
+        let (text, enabled, icon, accelerator, id, handler) = {
+            let state = self.state.lock_inner_ref()?;
+            (
+                state.text.clone(),
+                state.enabled,
+                state.icon.clone_ref(py),
+                state.accelerator.clone(),
+                state.id.clone(),
+                state.handler.as_ref().map(|handler| handler.clone_ref(py)),
+            )
+        };
+        let icon_or_native = icon.as_icon_or_native(py);
+
+        manager_method_impl!(py, &self.manager, |py, manager| IconMenuItem::new_impl(
+            py,
+            manager,
+            &text,
+            enabled,
+            icon_or_native,
+            accelerator.as_deref(),
+            id,
+            handler
+        ))?
+    }
 }
 
 macro_rules! native_icon_impl {
@@ -1609,6 +3090,16 @@ macro_rules! native_icon_impl {
             }
         }
 
+        #[pymethods]
+        impl $ident {
+            /// Whether the current platform honors [tauri::menu::NativeIcon]: upstream Tauri
+            /// only implements these on macOS, silently ignoring them (rather than erroring)
+            /// everywhere else.
+            #[staticmethod]
+            fn is_supported() -> bool {
+                cfg!(target_os = "macos")
+            }
+        }
     };
 }
 
@@ -1714,42 +3205,79 @@ macro_rules! context_menu_impl {
     };
 }
 
+/// Either a [ext_mod_impl::window::Window] or a [ext_mod_impl::webview::WebviewWindow], either of
+/// which can be passed to [ContextMenu::popup]/[ContextMenu::popup_at] (and the same methods on
+/// [Menu]/[Submenu]): both implement `raw_window_handle::HasWindowHandle`, which is all
+/// [tauri::menu::ContextMenu::popup] requires.
+#[derive(FromPyObject, IntoPyObject, IntoPyObjectRef)]
+#[non_exhaustive]
+pub enum ImplWindow {
+    Window(Py<ext_mod_impl::window::Window>),
+    WebviewWindow(Py<ext_mod_impl::webview::WebviewWindow>),
+}
+
+/// see [crate::manager_method_impl]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! popup_window_impl {
+    // impl
+    ($window:expr, $f0:expr, $f1:expr) => {{
+        use $crate::ext_mod_impl::menu::ImplWindow;
+
+        let window: &ImplWindow = $window;
+        match window {
+            ImplWindow::Window(v) => $f0(v.get().0.inner_ref().to_owned()),
+            ImplWindow::WebviewWindow(v) => $f1(v.get().0.inner_ref().to_owned()),
+        }
+    }};
+
+    // entry0
+    ($window:expr, $($f:tt)*) => {
+        popup_window_impl!($window, $($f)*, $($f)*)
+    };
+}
+
 /// See also: [tauri::menu::ContextMenu].
+///
+/// After popping this up with [Self::popup]/[Self::popup_at], see [Menu] for how to react to
+/// the item the user clicks.
 #[pyclass(frozen)]
 #[non_exhaustive]
 pub struct ContextMenu;
 
 #[pymethods]
 impl ContextMenu {
+    /// `window` may be either a [ext_mod_impl::window::Window] or a
+    /// [ext_mod_impl::webview::WebviewWindow].
     #[staticmethod]
-    fn popup(
-        py: Python<'_>,
-        slf: ImplContextMenu,
-        window: Py<ext_mod_impl::window::Window>,
-    ) -> PyResult<()> {
+    fn popup(py: Python<'_>, slf: ImplContextMenu, window: ImplWindow) -> PyResult<()> {
         py.allow_threads(|| {
-            let window = window.get().0.inner_ref().to_owned();
-            context_menu_impl!(&slf, |menu| {
-                menu.popup(window)
-                    .map_err(TauriError::from)
-                    .map_err(PyErr::from)
+            popup_window_impl!(&window, |window| {
+                context_menu_impl!(&slf, |menu| {
+                    menu.popup(window)
+                        .map_err(TauriError::from)
+                        .map_err(PyErr::from)
+                })
             })
         })
     }
 
+    /// `window` may be either a [ext_mod_impl::window::Window] or a
+    /// [ext_mod_impl::webview::WebviewWindow].
     #[staticmethod]
     fn popup_at(
         py: Python<'_>,
         slf: ImplContextMenu,
-        window: Py<ext_mod_impl::window::Window>,
+        window: ImplWindow,
         position: ext_mod_impl::Position,
     ) -> PyResult<()> {
         py.allow_threads(|| {
-            let window = window.get().0.inner_ref().to_owned();
-            context_menu_impl!(&slf, |menu| {
-                menu.popup_at(window, position)
-                    .map_err(TauriError::from)
-                    .map_err(PyErr::from)
+            popup_window_impl!(&window, |window| {
+                context_menu_impl!(&slf, |menu| {
+                    menu.popup_at(window, position)
+                        .map_err(TauriError::from)
+                        .map_err(PyErr::from)
+                })
             })
         })
     }
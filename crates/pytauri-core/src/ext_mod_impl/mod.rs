@@ -11,38 +11,74 @@ use std::{
     convert::Infallible,
     error::Error,
     fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
 use pyo3::{
     exceptions::PyNotImplementedError,
     exceptions::PyRuntimeError,
+    exceptions::PyValueError,
     marker::Ungil,
     prelude::*,
-    types::{PyInt, PyString},
+    pyclass::CompareOp,
+    types::{PyCFunction, PyInt, PyString},
     IntoPyObject,
 };
 use pyo3_utils::{
-    py_wrapper::{PyWrapper, PyWrapperT0, PyWrapperT2},
+    py_wrapper::{PyWrapper, PyWrapperT0, PyWrapperT1, PyWrapperT2},
     ungil::UnsafeUngilExt,
 };
-use tauri::{Listener as _, Manager as _};
+use serde_json::value::RawValue;
+use tauri::{Emitter as _, Listener as _, Manager as _};
 
 use crate::{
     delegate_inner,
     ext_mod_impl::{
         image::Image,
-        menu::{Menu, MenuEvent},
+        ipc::py_to_json_string,
+        menu::{self, Menu, MenuEvent},
         tray::{TrayIcon, TrayIconEvent},
-        webview::{TauriWebviewWindow, WebviewWindow},
+        webview::{self, TauriWebviewWindow, WebviewWindow},
+        window,
     },
     tauri_runtime::Runtime,
     utils::TauriError,
 };
 
 type TauriApp = tauri::App<Runtime>;
-type TauriAppHandle = tauri::AppHandle<Runtime>;
+pub(crate) type TauriAppHandle = tauri::AppHandle<Runtime>;
 type TauriContext = tauri::Context<Runtime>;
 
+/// tauri's own `ExitRequestApi` is a private type outside the `tauri` crate (see
+/// <https://github.com/tauri-apps/tauri/pull/12701>), so we can't wrap it by name. Instead, we
+/// box up a closure over it (naming a closure never requires naming the types it captures) and
+/// store that in a [PyWrapperT2], so calling [Self::prevent_exit] a second time raises
+/// `PyRuntimeError` via [pyo3_utils::py_wrapper::ConsumedError] instead of silently doing
+/// nothing.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct ExitRequestApi(PyWrapper<PyWrapperT2<Box<dyn FnOnce() + Send>>>);
+
+impl ExitRequestApi {
+    fn new(prevent_exit: impl FnOnce() + Send + 'static) -> Self {
+        let prevent_exit: Box<dyn FnOnce() + Send> = Box::new(prevent_exit);
+        Self(PyWrapper::new2(prevent_exit))
+    }
+}
+
+#[pymethods]
+impl ExitRequestApi {
+    /// Ask tauri not to exit despite this [RunEvent::ExitRequested].
+    fn prevent_exit(&self) -> PyResult<()> {
+        (self.0.try_take_inner()??)();
+        Ok(())
+    }
+}
+
 /// see also: [tauri::RunEvent]
 #[pyclass(frozen)]
 #[non_exhaustive]
@@ -53,22 +89,17 @@ pub enum RunEvent {
     #[non_exhaustive]
     ExitRequested {
         code: Option<Py<PyInt>>,
-        // TODO, XXX, FIXME: `ExitRequestApi` is a private type in `tauri`,
-        // we need create a issue to `tauri`, or we cant implement this.
-        // See: <https://github.com/tauri-apps/tauri/pull/12701>
-        // api: ExitRequestApi,
+        api: Py<ExitRequestApi>,
     },
     #[non_exhaustive]
     WindowEvent {
         label: Py<PyString>,
-        // TODO:
-        // event: WindowEvent,
+        event: Py<window::WindowEvent>,
     },
     #[non_exhaustive]
     WebviewEvent {
         label: Py<PyString>,
-        // TODO:
-        // event: WebviewEvent,
+        event: Py<webview::WebviewEvent>,
     },
     Ready(),
     Resumed(),
@@ -83,25 +114,26 @@ impl RunEvent {
     fn new(py: Python<'_>, value: tauri::RunEvent) -> PyResult<Self> {
         let ret = match value {
             tauri::RunEvent::Exit => Self::Exit(),
-            tauri::RunEvent::ExitRequested {
-                code, /* TODO */ ..
-            } => {
+            tauri::RunEvent::ExitRequested { code, api, .. } => {
                 let code = code.map(|code| {
                     let Ok(code) = code.into_pyobject(py);
                     code.unbind()
                 });
-                Self::ExitRequested { code }
+                let api = Py::new(py, ExitRequestApi::new(move || api.prevent_exit()))?;
+                Self::ExitRequested { code, api }
             }
-            tauri::RunEvent::WindowEvent {
-                label, /* TODO */ ..
-            } => Self::WindowEvent {
+            tauri::RunEvent::WindowEvent { label, event, .. } => Self::WindowEvent {
                 // if `label` is immutable, we can intern it to save memory.
                 label: PyString::intern(py, &label).unbind(),
+                event: window::WindowEvent::from_tauri(py, event)?
+                    .into_pyobject(py)?
+                    .unbind(),
             },
-            tauri::RunEvent::WebviewEvent {
-                label, /* TODO */ ..
-            } => Self::WebviewEvent {
+            tauri::RunEvent::WebviewEvent { label, event, .. } => Self::WebviewEvent {
                 label: PyString::intern(py, &label).unbind(),
+                event: webview::WebviewEvent::from_tauri(event)?
+                    .into_pyobject(py)?
+                    .unbind(),
             },
             tauri::RunEvent::Ready => Self::Ready(),
             tauri::RunEvent::Resumed => Self::Resumed(),
@@ -187,6 +219,11 @@ impl AppHandle {
                     Python::with_gil(|py| {
                         let app_handle: &Py<Self> = &moved_slf;
                         debug_assert_app_handle_py_is_rs(app_handle, _app_handle);
+
+                        // per-item handlers (see `MenuItem::set_handler` and friends) run
+                        // before the app-wide handler below.
+                        menu::MenuItemHandlers::dispatch(_app_handle, py, &menu_event.id.0);
+
                         let menu_event: Bound<'_, MenuEvent> =
                             MenuEvent::intern(py, &menu_event.id.0);
 
@@ -381,6 +418,26 @@ impl App {
         Ok(Self(PyWrapper::new2(app)))
     }
 
+    fn invoke_callback(
+        py: Python<'_>,
+        callback: &PyObject,
+        py_app_handle: &Py<AppHandle>,
+        py_run_event: RunEvent,
+    ) {
+        let callback = callback.bind(py);
+        let result = callback.call1((py_app_handle, py_run_event));
+        if let Err(e) = result {
+            // Use [write_unraisable] instead of [restore]:
+            // - Because we are about to panic, Python might abort
+            // - [restore] will not be handled in this case, so it will not be printed to stderr
+            e.write_unraisable(py, Some(callback));
+            // `panic` allows Python to exit `app.run()`,
+            // otherwise the Python main thread will be blocked by `app.run()`
+            // and unable to raise an error
+            panic!("Python exception occurred in callback")
+        }
+    }
+
     fn py_cb_to_rs_cb(
         callback: PyObject,
         app_handle: Py<AppHandle>,
@@ -390,27 +447,85 @@ impl App {
             debug_assert_app_handle_py_is_rs(&app_handle, _app_handle);
 
             Python::with_gil(|py| {
-                let py_run_event: RunEvent = RunEvent::new(py, run_event)
+                let py_run_event = RunEvent::new(py, run_event)
                     // TODO: maybe we should only `write_unraisable` and log it instead of `panic` here?
                     .expect("Failed to convert rust `RunEvent` to pyobject");
-
-                let callback = callback.bind(py);
-                let result = callback.call1((py_app_handle, py_run_event));
-                if let Err(e) = result {
-                    // Use [write_unraisable] instead of [restore]:
-                    // - Because we are about to panic, Python might abort
-                    // - [restore] will not be handled in this case, so it will not be printed to stderr
-                    e.write_unraisable(py, Some(callback));
-                    // `panic` allows Python to exit `app.run()`,
-                    // otherwise the Python main thread will be blocked by `app.run()`
-                    // and unable to raise an error
-                    panic!("Python exception occurred in callback")
-                }
+                Self::invoke_callback(py, &callback, py_app_handle, py_run_event);
             })
         }
     }
 
     fn noop_callback(_: &TauriAppHandle, _: tauri::RunEvent) {}
+
+    /// One tick of [Self::run_with_asyncio]: run a single [Self::run_iteration], then either
+    /// resolve `future` (if that iteration observed a [RunEvent::Exit]) or schedule the next tick
+    /// via `event_loop.call_soon`. Recurses through the event loop rather than Rust's call stack,
+    /// so it costs no more stack space than any other `asyncio` callback chain.
+    fn run_with_asyncio_tick(
+        py: Python<'_>,
+        app: Py<App>,
+        event_loop: PyObject,
+        future: PyObject,
+        callback: Option<PyObject>,
+    ) -> PyResult<()> {
+        let exited = Arc::new(AtomicBool::new(false));
+
+        // Wrap `callback` (if any) so we can notice a `RunEvent::Exit` without the caller having
+        // to check for it themselves.
+        let wrapped_callback = {
+            let exited = exited.clone();
+            let callback = callback.as_ref().map(|callback| callback.clone_ref(py));
+            PyCFunction::new_closure(
+                py,
+                Some(c"_pytauri_run_with_asyncio_callback"),
+                None,
+                move |args, kwargs| -> PyResult<PyObject> {
+                    Python::with_gil(|py| {
+                        if let Ok(run_event) = args.get_item(1)?.downcast::<RunEvent>() {
+                            if matches!(&*run_event.borrow(), RunEvent::Exit()) {
+                                exited.store(true, Ordering::Release);
+                            }
+                        }
+                        match &callback {
+                            Some(callback) => Ok(callback.bind(py).call(args, kwargs)?.unbind()),
+                            None => Ok(py.None()),
+                        }
+                    })
+                },
+            )?
+            .into_any()
+            .unbind()
+        };
+
+        app.borrow(py).run_iteration(py, Some(wrapped_callback))?;
+
+        if exited.load(Ordering::Acquire) {
+            future.bind(py).call_method1("set_result", (py.None(),))?;
+            return Ok(());
+        }
+
+        let event_loop_for_call_soon = event_loop.clone_ref(py);
+        let reschedule = PyCFunction::new_closure(
+            py,
+            Some(c"_pytauri_run_with_asyncio_reschedule"),
+            None,
+            move |_args, _kwargs| -> PyResult<()> {
+                Python::with_gil(|py| {
+                    Self::run_with_asyncio_tick(
+                        py,
+                        app.clone_ref(py),
+                        event_loop.clone_ref(py),
+                        future.clone_ref(py),
+                        callback.as_ref().map(|callback| callback.clone_ref(py)),
+                    )
+                })
+            },
+        )?;
+        event_loop_for_call_soon
+            .bind(py)
+            .call_method1("call_soon", (reschedule,))?;
+        Ok(())
+    }
 }
 
 #[pymethods]
@@ -431,11 +546,24 @@ impl App {
         }
     }
 
+    /// Pumps exactly one iteration of the native event loop, instead of [Self::run]'s blocking
+    /// "hand the whole process over" loop, so a Python driver (e.g. an `asyncio`/`trio` loop) can
+    /// interleave its own scheduling between calls — `await`ing `asyncio.sleep(0)` between
+    /// iterations, say.
+    ///
+    /// `callback` runs with the GIL held (same as [Self::run]'s), but the iteration step itself
+    /// releases it, mirroring [AllowThreadsUnsend](pyfuture::future::AllowThreadsUnsend).
+    ///
+    /// # Reentrancy
+    ///
+    /// `self`'s inner `tauri::App` is locked for the duration of this call, so calling
+    /// `run_iteration` again from inside `callback` (e.g. a Python coroutine scheduled from it
+    /// that re-enters the loop) fails fast with a [PyErr] instead of deadlocking.
     #[pyo3(signature = (callback = None, /))]
     fn run_iteration(&self, py: Python<'_>, callback: Option<PyObject>) -> PyResult<()> {
         let app = self.0.try_lock_inner_mut()??;
         let py_app_handle = app.py_app_handle().clone_ref(py);
-        unsafe {
+        let result = unsafe {
             // `&mut tauri::App` does not hold the GIL, so this is safe
             py.allow_threads_unsend(app, |mut app| {
                 match callback {
@@ -446,7 +574,49 @@ impl App {
                 }
                 Ok(())
             })
+        };
+        // Opportunistic flush point for `pyfuture`'s queued `log`-crate records (e.g. a
+        // `RustFuture` dropped while still running): we already hold the GIL here, and every
+        // `asyncio`/`trio` driver loop calls `run_iteration` regularly.
+        pyfuture::log::flush_queued_logs(py);
+        result
+    }
+
+    /// Drives [Self::run_iteration] cooperatively on a Python `asyncio` event loop, instead of
+    /// [Self::run]'s blocking "hand the whole process over" loop: each tick runs one
+    /// `run_iteration`, then reschedules itself via `event_loop.call_soon` so `asyncio` tasks get
+    /// a turn between iterations. Returns an `asyncio.Future` that resolves once a
+    /// [RunEvent::Exit] is observed, so `await app.run_with_asyncio(loop, callback)` returns
+    /// cleanly on shutdown.
+    ///
+    /// `callback` is invoked the same way as [Self::run_iteration]'s; `run_with_asyncio` only
+    /// inspects the [RunEvent] it's given to notice [RunEvent::Exit], it doesn't consume it.
+    #[pyo3(signature = (event_loop, callback = None, /))]
+    fn run_with_asyncio(
+        slf: Py<Self>,
+        py: Python<'_>,
+        event_loop: PyObject,
+        callback: Option<PyObject>,
+    ) -> PyResult<PyObject> {
+        let future = event_loop.bind(py).call_method0("create_future")?.unbind();
+        Self::run_with_asyncio_tick(py, slf, event_loop, future.clone_ref(py), callback)?;
+        Ok(future)
+    }
+
+    /// Pump [Self::run_iteration] up to `max_iterations` times, so that `PyFuture`s resolved by
+    /// Python tasks scheduled on prior iterations (e.g. an async command invoked via
+    /// [webview::Webview::mock_ipc_invoke]) get a chance to run to completion before the test
+    /// asserts on their result, instead of the caller having to hand-loop `run_iteration` itself.
+    ///
+    /// Does not detect "nothing left to do" and stop early: it always runs exactly
+    /// `max_iterations` iterations. Only meaningful when [crate::tauri_runtime::Runtime] is the
+    /// mock runtime, i.e. built with the `__test` Cargo feature.
+    #[cfg(feature = "__test")]
+    fn mock_run_until_idle(&self, py: Python<'_>, max_iterations: usize) -> PyResult<()> {
+        for _ in 0..max_iterations {
+            self.run_iteration(py, None)?;
         }
+        Ok(())
     }
 
     fn cleanup_before_exit(&self, py: Python<'_>) -> PyResult<()> {
@@ -584,6 +754,25 @@ macro_rules! manager_method_impl {
     };
 }
 
+/// Backs [Manager::manage]/[Manager::state]: a single app-wide map of arbitrary Python values,
+/// lazily managed into Tauri's state container the same way [PyAppHandle] is.
+//
+// NOTE: due to the unsoundness of [Manager::unmanage] (see [PyAppHandleExt]'s note), entries are
+// never removed once inserted, and `PyStateMap` itself is never unmanaged.
+struct PyStateMap(PyWrapper<PyWrapperT1<HashMap<String, PyObject>>>);
+
+fn get_or_init_py_state_map<M: tauri::Manager<Runtime>>(manager: &M) -> &PyStateMap {
+    if manager.try_state::<PyStateMap>().is_none() {
+        let not_yet_managed = manager.manage(PyStateMap(PyWrapper::new1(HashMap::new())));
+        debug_assert!(
+            not_yet_managed,
+            "`PyStateMap` is private, so it is impossible for other crates to manage it, \
+            and for self crate, it should be initialized only once."
+        );
+    }
+    manager.state::<PyStateMap>().inner()
+}
+
 /// See also: [tauri::Manager].
 #[pyclass(frozen)]
 #[non_exhaustive]
@@ -599,6 +788,36 @@ impl Manager {
             .clone_ref(py))
     }
 
+    /// Stash `value` under `key` in a pytauri-owned, app-wide state map, so it can be retrieved
+    /// later (from a command handler, an event callback, or anywhere else with access to an
+    /// [ImplManager]) via [Self::state]. Unlike [tauri::Manager::manage], this is keyed by an
+    /// arbitrary string instead of by Rust type, and re-`manage`-ing the same key overwrites the
+    /// previous value rather than failing.
+    #[staticmethod]
+    fn manage(py: Python<'_>, slf: ImplManager, key: Cow<'_, str>, value: PyObject) -> PyResult<()> {
+        manager_method_impl!(py, &slf, |_py, manager| {
+            let state_map = get_or_init_py_state_map(manager);
+            state_map.0.lock_inner_mut().map(|mut map| {
+                map.insert(key.into_owned(), value);
+            })
+        })??;
+        Ok(())
+    }
+
+    /// Retrieve a value previously stashed via [Self::manage], or [None] if `key` was never
+    /// `manage`d.
+    #[staticmethod]
+    fn state(py: Python<'_>, slf: ImplManager, key: Cow<'_, str>) -> PyResult<Option<PyObject>> {
+        let value = manager_method_impl!(py, &slf, |py, manager| {
+            let state_map = get_or_init_py_state_map(manager);
+            state_map
+                .0
+                .lock_inner_ref()
+                .map(|map| map.get(key.as_ref()).map(|value| value.clone_ref(py)))
+        })??;
+        Ok(value)
+    }
+
     #[staticmethod]
     fn get_webview_window(
         py: Python<'_>,
@@ -623,6 +842,35 @@ impl Manager {
                 .collect::<_>()
         })
     }
+
+    /// Subscribe to `slf`'s menu events as an async iterator, see [menu::MenuEventStream].
+    ///
+    /// Tauri distinguishes window-scoped menu event listeners from app-wide ones; registering
+    /// through a `WebviewWindow` scopes the subscription to that window, while `App`/`AppHandle`
+    /// is app-wide, just like [AppHandle::on_menu_event] but event-loop-driven instead of
+    /// callback-driven. `maxsize` is forwarded to the backing `asyncio.Queue` (`0` means
+    /// unbounded).
+    #[staticmethod]
+    #[pyo3(signature = (slf, maxsize=0))]
+    fn menu_events(
+        py: Python<'_>,
+        slf: ImplManager,
+        maxsize: usize,
+    ) -> PyResult<Py<menu::MenuEventStream>> {
+        let stream = Py::new(py, menu::MenuEventStream::new(py, maxsize)?)?;
+        let moved_stream = stream.clone_ref(py);
+        manager_method_impl!(py, &slf, [ungil], move |manager| {
+            manager.on_menu_event(move |app_handle, menu_event| {
+                Python::with_gil(|py| {
+                    // per-item handlers (see `MenuItem::set_handler` and friends) run before
+                    // the event is pushed to the stream, same as `AppHandle::on_menu_event`.
+                    menu::MenuItemHandlers::dispatch(app_handle, py, &menu_event.id.0);
+                    moved_stream.get().push(py, &menu_event.id.0);
+                })
+            });
+        })?;
+        Ok(stream)
+    }
 }
 
 /// See also: [tauri::EventId].
@@ -656,13 +904,40 @@ impl Listener {
                 };
                 let pyobj = pyobj.bind(py);
                 let result = pyobj.call1((event,));
-                if let Err(e) = result {
-                    e.write_unraisable(py, Some(pyobj));
-                    panic!("Python exception occurred in Listener handler")
+                match result {
+                    Ok(result) => Self::spawn_if_coroutine(py, pyobj, result),
+                    Err(e) => {
+                        e.write_unraisable(py, Some(pyobj));
+                        panic!("Python exception occurred in Listener handler")
+                    }
                 }
             })
         }
     }
+
+    /// `handler` may be an `async def`, in which case calling it (above) only constructs a
+    /// coroutine without running its body. Detect that (`result` is awaitable) and drive it to
+    /// completion on Tauri's async runtime instead of silently dropping it, so `async def
+    /// on_event(event): await ...` handlers actually get to do their async work. A plain `def`
+    /// handler's `result` isn't awaitable, so this is a no-op for it.
+    fn spawn_if_coroutine(py: Python<'_>, handler: &Bound<'_, PyAny>, result: Bound<'_, PyAny>) {
+        if !result.hasattr("__await__").unwrap_or(false) {
+            return;
+        }
+        let coroutine = result.unbind();
+        let handler = handler.clone().unbind();
+        tauri::async_runtime::spawn(async move {
+            let future =
+                Python::with_gil(|py| pyo3_async_runtimes::tokio::into_future(coroutine.bind(py).clone()));
+            let result = match future {
+                Ok(future) => future.await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                Python::with_gil(|py| e.write_unraisable(py, Some(handler.bind(py))));
+            }
+        });
+    }
 }
 
 #[pymethods]
@@ -717,8 +992,126 @@ impl Listener {
     }
 }
 
+/// see also: [tauri::EventTarget]
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub enum EventTarget {
+    Any(),
+    AnyLabel(Py<PyString>),
+    App(),
+    Window(Py<PyString>),
+    Webview(Py<PyString>),
+    WebviewWindow(Py<PyString>),
+}
+
+impl EventTarget {
+    fn from_tauri(py: Python<'_>, target: &tauri::EventTarget) -> Self {
+        match target {
+            tauri::EventTarget::Any => Self::Any(),
+            tauri::EventTarget::AnyLabel { label } => {
+                Self::AnyLabel(PyString::new(py, label).unbind())
+            }
+            tauri::EventTarget::App => Self::App(),
+            tauri::EventTarget::Window { label } => {
+                Self::Window(PyString::new(py, label).unbind())
+            }
+            tauri::EventTarget::Webview { label } => {
+                Self::Webview(PyString::new(py, label).unbind())
+            }
+            tauri::EventTarget::WebviewWindow { label } => {
+                Self::WebviewWindow(PyString::new(py, label).unbind())
+            }
+        }
+    }
+}
+
+/// The Implementers of [tauri::Emitter].
+pub type ImplEmitter = ImplManager;
+
+/// Convert an arbitrary JSON-serializable Python object into a payload [tauri::Emitter]'s
+/// methods can send, without re-parsing the JSON we just got `json.dumps` to produce for us.
+fn py_to_json_payload(py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<Box<RawValue>> {
+    let json = py_to_json_string(py, value)?;
+    RawValue::from_string(json).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// See also: [tauri::Emitter].
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct Emitter;
+
+#[pymethods]
+impl Emitter {
+    #[staticmethod]
+    fn emit(
+        py: Python<'_>,
+        slf: ImplEmitter,
+        event: Cow<'_, str>,
+        payload: Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let payload = py_to_json_payload(py, &payload)?;
+        manager_method_impl!(py, &slf, [ungil], move |manager| manager
+            .emit(&event, payload)
+            .map_err(TauriError::from))??;
+        Ok(())
+    }
+
+    #[staticmethod]
+    fn emit_to(
+        py: Python<'_>,
+        slf: ImplEmitter,
+        label: Cow<'_, str>,
+        event: Cow<'_, str>,
+        payload: Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let payload = py_to_json_payload(py, &payload)?;
+        manager_method_impl!(py, &slf, [ungil], move |manager| manager
+            .emit_to(label.as_ref(), &event, payload)
+            .map_err(TauriError::from))??;
+        Ok(())
+    }
+
+    /// `predicate` is called once per subscribed [EventTarget] and must return a [bool] deciding
+    /// whether that target receives the event.
+    #[staticmethod]
+    fn emit_filter(
+        py: Python<'_>,
+        slf: ImplEmitter,
+        event: Cow<'_, str>,
+        payload: Bound<'_, PyAny>,
+        predicate: PyObject,
+    ) -> PyResult<()> {
+        let payload = py_to_json_payload(py, &payload)?;
+        manager_method_impl!(py, &slf, [ungil], move |manager| manager
+            .emit_filter(&event, payload, |target| {
+                Python::with_gil(|py| {
+                    let py_target = EventTarget::from_tauri(py, target);
+                    let result = predicate.bind(py).call1((py_target,)).and_then(|r| r.extract());
+                    result.unwrap_or_else(|e| {
+                        e.write_unraisable(py, Some(predicate.bind(py)));
+                        false
+                    })
+                })
+            })
+            .map_err(TauriError::from))??;
+        Ok(())
+    }
+}
+
+/// `__richcmp__`/the derived [PartialEq] compare `f64`s with IEEE `==`, under which `0.0 ==
+/// -0.0`; normalize to `0.0` before hashing a float so the two still hash equally, as
+/// `__hash__`/`__eq__` requires. `NaN` needs no such treatment: it compares unequal to
+/// everything (including itself), so a `NaN`'s hash is never required to match anything.
+fn normalize_zero(x: f64) -> f64 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x
+    }
+}
+
 /// see also: [tauri::Position]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 #[pyclass(frozen)]
 pub enum Position {
     /// `x, y`
@@ -745,8 +1138,76 @@ impl From<tauri::Position> for Position {
     }
 }
 
+impl Position {
+    fn repr(&self) -> String {
+        match *self {
+            Position::Physical(x, y) => format!("Position.Physical(x={x}, y={y})"),
+            Position::Logical(x, y) => format!("Position.Logical(x={x}, y={y})"),
+        }
+    }
+}
+
+#[pymethods]
+impl Position {
+    /// see also: [tauri::PhysicalPosition::to_logical]
+    ///
+    /// A no-op if `self` is already [Position::Logical].
+    fn to_logical(&self, scale_factor: f64) -> Self {
+        match *self {
+            Position::Physical(x, y) => {
+                Position::Logical(x as f64 / scale_factor, y as f64 / scale_factor)
+            }
+            Position::Logical(..) => *self,
+        }
+    }
+
+    /// see also: [tauri::LogicalPosition::to_physical]
+    ///
+    /// A no-op if `self` is already [Position::Physical].
+    fn to_physical(&self, scale_factor: f64) -> Self {
+        match *self {
+            Position::Logical(x, y) => {
+                Position::Physical((x * scale_factor).round() as i32, (y * scale_factor).round() as i32)
+            }
+            Position::Physical(..) => *self,
+        }
+    }
+
+    /// `Physical`/`Logical` compare unequal rather than raising; only `==`/`!=` are supported.
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyResult<PyObject> {
+        match op {
+            CompareOp::Eq => Ok((self == other).into_pyobject(py)?.into_any().unbind()),
+            CompareOp::Ne => Ok((self != other).into_pyobject(py)?.into_any().unbind()),
+            _ => Err(PyNotImplementedError::new_err(
+                "`Position` only supports `==`/`!=`",
+            )),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        match *self {
+            Position::Physical(x, y) => {
+                0u8.hash(&mut hasher);
+                x.hash(&mut hasher);
+                y.hash(&mut hasher);
+            }
+            Position::Logical(x, y) => {
+                1u8.hash(&mut hasher);
+                normalize_zero(x).to_bits().hash(&mut hasher);
+                normalize_zero(y).to_bits().hash(&mut hasher);
+            }
+        }
+        hasher.finish() as isize
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
 /// see also: [tauri::Size]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 #[pyclass(frozen)]
 pub enum Size {
     /// `width, height`
@@ -777,6 +1238,77 @@ impl From<tauri::Size> for Size {
     }
 }
 
+impl Size {
+    fn repr(&self) -> String {
+        match *self {
+            Size::Physical(width, height) => {
+                format!("Size.Physical(width={width}, height={height})")
+            }
+            Size::Logical(width, height) => {
+                format!("Size.Logical(width={width}, height={height})")
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl Size {
+    /// see also: [tauri::PhysicalSize::to_logical]
+    ///
+    /// A no-op if `self` is already [Size::Logical].
+    fn to_logical(&self, scale_factor: f64) -> Self {
+        match *self {
+            Size::Physical(width, height) => {
+                Size::Logical(width as f64 / scale_factor, height as f64 / scale_factor)
+            }
+            Size::Logical(..) => *self,
+        }
+    }
+
+    /// see also: [tauri::LogicalSize::to_physical]
+    ///
+    /// A no-op if `self` is already [Size::Physical].
+    fn to_physical(&self, scale_factor: f64) -> Self {
+        match *self {
+            Size::Logical(width, height) => Size::Physical(
+                (width * scale_factor).round() as u32,
+                (height * scale_factor).round() as u32,
+            ),
+            Size::Physical(..) => *self,
+        }
+    }
+
+    /// `Physical`/`Logical` compare unequal rather than raising; only `==`/`!=` are supported.
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyResult<PyObject> {
+        match op {
+            CompareOp::Eq => Ok((self == other).into_pyobject(py)?.into_any().unbind()),
+            CompareOp::Ne => Ok((self != other).into_pyobject(py)?.into_any().unbind()),
+            _ => Err(PyNotImplementedError::new_err("`Size` only supports `==`/`!=`")),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        match *self {
+            Size::Physical(width, height) => {
+                0u8.hash(&mut hasher);
+                width.hash(&mut hasher);
+                height.hash(&mut hasher);
+            }
+            Size::Logical(width, height) => {
+                1u8.hash(&mut hasher);
+                normalize_zero(width).to_bits().hash(&mut hasher);
+                normalize_zero(height).to_bits().hash(&mut hasher);
+            }
+        }
+        hasher.finish() as isize
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
 /// see also: [tauri::Rect]
 #[pyclass(frozen)]
 pub struct Rect {
@@ -803,6 +1335,48 @@ impl Rect {
             size: Size::from(rect.size).into_pyobject(py)?.unbind(),
         })
     }
+
+    /// `(is_physical, left, top, right, bottom)`, erroring if `position`/`size` disagree on
+    /// `Physical`/`Logical`-ness (there's no `scale_factor` in scope here to reconcile them).
+    fn edges(&self) -> PyResult<(bool, f64, f64, f64, f64)> {
+        let (position_is_physical, x, y) = match *self.position.get() {
+            Position::Physical(x, y) => (true, x as f64, y as f64),
+            Position::Logical(x, y) => (false, x, y),
+        };
+        let (size_is_physical, width, height) = match *self.size.get() {
+            Size::Physical(width, height) => (true, width as f64, height as f64),
+            Size::Logical(width, height) => (false, width, height),
+        };
+        if position_is_physical != size_is_physical {
+            return Err(PyValueError::new_err(
+                "`Rect.position` and `Rect.size` must be the same variant (both `Physical` or both `Logical`)",
+            ));
+        }
+        Ok((position_is_physical, x, y, x + width, y + height))
+    }
+
+    fn from_edges(
+        py: Python<'_>,
+        is_physical: bool,
+        left: f64,
+        top: f64,
+        right: f64,
+        bottom: f64,
+    ) -> PyResult<Self> {
+        let (width, height) = (right - left, bottom - top);
+        let (position, size) = if is_physical {
+            (
+                Position::Physical(left.round() as i32, top.round() as i32),
+                Size::Physical(width.round() as u32, height.round() as u32),
+            )
+        } else {
+            (Position::Logical(left, top), Size::Logical(width, height))
+        };
+        Ok(Self {
+            position: position.into_pyobject(py)?.unbind(),
+            size: size.into_pyobject(py)?.unbind(),
+        })
+    }
 }
 
 #[pymethods]
@@ -812,4 +1386,91 @@ impl Rect {
     fn __new__(position: Py<Position>, size: Py<Size>) -> Self {
         Self { position, size }
     }
+
+    /// Compares `position`/`size` by value (dereferencing the `Py<Position>`/`Py<Size>` fields)
+    /// rather than by identity. Only `==`/`!=` are supported.
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyResult<PyObject> {
+        let eq = *self.position.get() == *other.position.get() && *self.size.get() == *other.size.get();
+        match op {
+            CompareOp::Eq => Ok(eq.into_pyobject(py)?.into_any().unbind()),
+            CompareOp::Ne => Ok((!eq).into_pyobject(py)?.into_any().unbind()),
+            _ => Err(PyNotImplementedError::new_err("`Rect` only supports `==`/`!=`")),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Rect(position={}, size={})",
+            self.position.get().repr(),
+            self.size.get().repr()
+        )
+    }
+
+    /// Whether `point` falls within `self`, treating `self` as a half-open `[left, right) x
+    /// [top, bottom)` box. Errors if `point` isn't the same `Physical`/`Logical` variant as
+    /// `self`.
+    fn contains(&self, point: Py<Position>) -> PyResult<bool> {
+        let (is_physical, left, top, right, bottom) = self.edges()?;
+        let (point_is_physical, x, y) = match *point.get() {
+            Position::Physical(x, y) => (true, x as f64, y as f64),
+            Position::Logical(x, y) => (false, x, y),
+        };
+        if point_is_physical != is_physical {
+            return Err(PyValueError::new_err(
+                "`point` must be the same variant (`Physical`/`Logical`) as `self`",
+            ));
+        }
+        Ok(x >= left && x < right && y >= top && y < bottom)
+    }
+
+    /// Whether `self` and `other` overlap. Errors if the two `Rect`s don't share a
+    /// `Physical`/`Logical` variant.
+    fn intersects(&self, other: Py<Rect>) -> PyResult<bool> {
+        let (is_physical, left, top, right, bottom) = self.edges()?;
+        let (other_is_physical, o_left, o_top, o_right, o_bottom) = other.get().edges()?;
+        if is_physical != other_is_physical {
+            return Err(PyValueError::new_err(
+                "`other` must be the same variant (`Physical`/`Logical`) as `self`",
+            ));
+        }
+        Ok(left < o_right && o_left < right && top < o_bottom && o_top < bottom)
+    }
+
+    /// The overlapping region of `self` and `other`, or `None` if they don't intersect. Errors
+    /// if the two `Rect`s don't share a `Physical`/`Logical` variant.
+    fn intersection(&self, py: Python<'_>, other: Py<Rect>) -> PyResult<Option<Rect>> {
+        let (is_physical, left, top, right, bottom) = self.edges()?;
+        let (other_is_physical, o_left, o_top, o_right, o_bottom) = other.get().edges()?;
+        if is_physical != other_is_physical {
+            return Err(PyValueError::new_err(
+                "`other` must be the same variant (`Physical`/`Logical`) as `self`",
+            ));
+        }
+        let (i_left, i_top) = (left.max(o_left), top.max(o_top));
+        let (i_right, i_bottom) = (right.min(o_right), bottom.min(o_bottom));
+        if i_left >= i_right || i_top >= i_bottom {
+            return Ok(None);
+        }
+        Self::from_edges(py, is_physical, i_left, i_top, i_right, i_bottom).map(Some)
+    }
+
+    /// The smallest `Rect` containing both `self` and `other`. Errors if the two `Rect`s don't
+    /// share a `Physical`/`Logical` variant.
+    fn union(&self, py: Python<'_>, other: Py<Rect>) -> PyResult<Rect> {
+        let (is_physical, left, top, right, bottom) = self.edges()?;
+        let (other_is_physical, o_left, o_top, o_right, o_bottom) = other.get().edges()?;
+        if is_physical != other_is_physical {
+            return Err(PyValueError::new_err(
+                "`other` must be the same variant (`Physical`/`Logical`) as `self`",
+            ));
+        }
+        Self::from_edges(
+            py,
+            is_physical,
+            left.min(o_left),
+            top.min(o_top),
+            right.max(o_right),
+            bottom.max(o_bottom),
+        )
+    }
 }
@@ -7,7 +7,7 @@ use pyo3::{
     FromPyObject, IntoPyObject,
 };
 use pyo3_utils::{
-    py_wrapper::{PyWrapper, PyWrapperT0},
+    py_wrapper::{PyWrapper, PyWrapperT0, PyWrapperT1},
     ungil::UnsafeUngilExt,
 };
 use tauri::tray;
@@ -186,6 +186,192 @@ impl TrayIcon {
     }
 }
 
+struct TrayIconBuilderState {
+    id: Option<String>,
+    icon: Option<Py<ext_mod_impl::image::Image>>,
+    menu: Option<ImplContextMenu>,
+    tooltip: Option<String>,
+    title: Option<String>,
+    menu_on_left_click: Option<bool>,
+    icon_as_template: Option<bool>,
+    temp_dir_path: Option<PathBuf>,
+    on_tray_icon_event: Option<Py<PyAny>>,
+    on_menu_event: Option<Py<PyAny>>,
+}
+
+/// A fluent builder for [TrayIcon], mirroring [tauri::tray::TrayIconBuilder].
+///
+/// Unlike [TrayIcon::__new__]/[TrayIcon::with_id], every field here is optional and accumulated
+/// before [Self::build] does the actual construction: it creates the underlying bare tray icon
+/// and then applies every field that was set via the corresponding `TrayIcon::set_*` method.
+/// [Self::on_tray_icon_event]/[Self::on_menu_event], if set, are registered via
+/// [TrayIcon::on_tray_icon_event]/[TrayIcon::on_menu_event] once the icon is built — this one
+/// necessarily happens post-build, since the handler is called with the already-boxed `Py<TrayIcon>`
+/// as its first argument (see [TrayIcon::on_tray_icon_event]'s comment), which can't exist before
+/// the icon does.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct TrayIconBuilder {
+    manager: ImplManager,
+    state: PyWrapper<PyWrapperT1<TrayIconBuilderState>>,
+}
+
+impl TrayIconBuilder {
+    fn new(manager: ImplManager) -> Self {
+        Self {
+            manager,
+            state: PyWrapper::new1(TrayIconBuilderState {
+                id: None,
+                icon: None,
+                menu: None,
+                tooltip: None,
+                title: None,
+                menu_on_left_click: None,
+                icon_as_template: None,
+                temp_dir_path: None,
+                on_tray_icon_event: None,
+                on_menu_event: None,
+            }),
+        }
+    }
+}
+
+#[pymethods]
+impl TrayIconBuilder {
+    #[new]
+    fn __new__(manager: ImplManager) -> Self {
+        Self::new(manager)
+    }
+
+    fn id(slf: Py<Self>, id: String) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.id = Some(id);
+        Ok(slf)
+    }
+
+    fn icon(slf: Py<Self>, icon: Option<Py<ext_mod_impl::image::Image>>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.icon = icon;
+        Ok(slf)
+    }
+
+    fn menu(slf: Py<Self>, menu: Option<ImplContextMenu>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.menu = menu;
+        Ok(slf)
+    }
+
+    fn tooltip(slf: Py<Self>, tooltip: Option<String>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.tooltip = tooltip;
+        Ok(slf)
+    }
+
+    fn menu_on_left_click(slf: Py<Self>, enable: bool) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.menu_on_left_click = Some(enable);
+        Ok(slf)
+    }
+
+    fn title(slf: Py<Self>, title: Option<String>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.title = title;
+        Ok(slf)
+    }
+
+    fn icon_as_template(slf: Py<Self>, is_template: bool) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.icon_as_template = Some(is_template);
+        Ok(slf)
+    }
+
+    // PERF: see [TrayIcon::set_temp_dir_path]'s comment on why this takes an owned `PathBuf`.
+    fn temp_dir_path(slf: Py<Self>, path: Option<PathBuf>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.temp_dir_path = path;
+        Ok(slf)
+    }
+
+    /// Register a handler for [TrayIcon::on_tray_icon_event], called once [Self::build] succeeds.
+    fn on_tray_icon_event(slf: Py<Self>, handler: Py<PyAny>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.on_tray_icon_event = Some(handler);
+        Ok(slf)
+    }
+
+    /// Register a handler for [TrayIcon::on_menu_event], called once [Self::build] succeeds.
+    fn on_menu_event(slf: Py<Self>, handler: Py<PyAny>) -> PyResult<Py<Self>> {
+        slf.get().state.lock_inner_mut()?.on_menu_event = Some(handler);
+        Ok(slf)
+    }
+
+    /// Construct the [TrayIcon] from the accumulated fields. The builder keeps its fields, so
+    /// `build` can be called again.
+    fn build(&self, py: Python<'_>) -> PyResult<Py<TrayIcon>> {
+        let (
+            id,
+            icon,
+            menu,
+            tooltip,
+            title,
+            menu_on_left_click,
+            icon_as_template,
+            temp_dir_path,
+            on_tray_icon_event,
+            on_menu_event,
+        ) = {
+            let state = self.state.lock_inner_ref()?;
+            (
+                state.id.clone(),
+                state.icon.as_ref().map(|icon| icon.clone_ref(py)),
+                state.menu.as_ref().map(|menu| match menu {
+                    ImplContextMenu::Menu(v) => ImplContextMenu::Menu(v.clone_ref(py)),
+                    ImplContextMenu::Submenu(v) => ImplContextMenu::Submenu(v.clone_ref(py)),
+                }),
+                state.tooltip.clone(),
+                state.title.clone(),
+                state.menu_on_left_click,
+                state.icon_as_template,
+                state.temp_dir_path.clone(),
+                state
+                    .on_tray_icon_event
+                    .as_ref()
+                    .map(|handler| handler.clone_ref(py)),
+                state
+                    .on_menu_event
+                    .as_ref()
+                    .map(|handler| handler.clone_ref(py)),
+            )
+        };
+
+        let tray_icon = manager_method_impl!(py, &self.manager, |py, manager| {
+            TrayIcon::new_impl(py, manager, id.map(tray::TrayIconId))
+        })??;
+        let tray_icon = Py::new(py, tray_icon)?;
+
+        if let Some(icon) = icon {
+            tray_icon.get().set_icon(py, Some(icon))?;
+        }
+        if let Some(menu) = menu {
+            tray_icon.get().set_menu(py, Some(menu))?;
+        }
+        if let Some(tooltip) = tooltip {
+            tray_icon.get().set_tooltip(py, Some(&tooltip))?;
+        }
+        if let Some(title) = title {
+            tray_icon.get().set_title(py, Some(&title))?;
+        }
+        if let Some(enable) = menu_on_left_click {
+            tray_icon.get().set_show_menu_on_left_click(py, enable)?;
+        }
+        if let Some(is_template) = icon_as_template {
+            tray_icon.get().set_icon_as_template(py, is_template)?;
+        }
+        if let Some(path) = temp_dir_path {
+            tray_icon.get().set_temp_dir_path(py, Some(path))?;
+        }
+        if let Some(handler) = on_tray_icon_event {
+            TrayIcon::on_tray_icon_event(tray_icon.clone_ref(py), py, handler);
+        }
+        if let Some(handler) = on_menu_event {
+            tray_icon.get().on_menu_event(py, handler);
+        }
+
+        Ok(tray_icon)
+    }
+}
+
 /// see also: [tauri::tray::TrayIconEvent::Click::position]
 ///
 /// `tuple[x: float, y: float]`
@@ -1,12 +1,12 @@
-use pyo3::{prelude::*, types::PyString};
+use pyo3::{exceptions::PyNotImplementedError, prelude::*, types::PyString};
 use pyo3_utils::py_wrapper::{PyWrapper, PyWrapperT0};
-use tauri::webview;
+use tauri::{webview, Manager as _};
 
 use crate::{
     context_menu_impl,
     ext_mod_impl::{
         image::Image,
-        menu::{ImplContextMenu, Menu, MenuEvent},
+        menu::{ImplContextMenu, Menu, MenuEvent, MenuItemHandlers},
         window::Window,
         Position,
     },
@@ -14,6 +14,27 @@ use crate::{
     utils::TauriError,
 };
 
+/// see also: [tauri::webview::WebviewEvent]
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub enum WebviewEvent {
+    DomReady(),
+}
+
+impl WebviewEvent {
+    pub(crate) fn from_tauri(event: webview::WebviewEvent) -> PyResult<Self> {
+        let event = match event {
+            webview::WebviewEvent::DomReady => Self::DomReady(),
+            event => {
+                return Err(PyNotImplementedError::new_err(format!(
+                    "Please make a issue for unimplemented WebviewEvent: {event:?}",
+                )))
+            }
+        };
+        Ok(event)
+    }
+}
+
 pub(crate) type TauriWebviewWindow = webview::WebviewWindow<Runtime>;
 type TauriWebview = webview::Webview<Runtime>;
 
@@ -68,13 +89,22 @@ impl WebviewWindow {
             slf.get()
                 .0
                 .inner_ref()
-                .on_menu_event(move |_window, menu_event| {
+                .on_menu_event(move |tauri_window, menu_event| {
                     Python::with_gil(|py| {
                         // See: <https://github.com/tauri-apps/tauri/blob/8e9339e8807338597132ffd8688fb9da00f4102b/crates/tauri/src/app.rs#L2168-L2184>,
                         // The `window` argument is always the `WebviewWindow` instance that calls this method,
                         // so we can directly use the same PyObject.
                         let window: &Py<Self> = &moved_slf;  // TODO, XXX, FIXME: return `Window` instead of `WebviewWindow`?
-                        debug_assert_eq!(&*window.get().0.inner_ref().as_ref().window_ref(), _window);
+                        debug_assert_eq!(
+                            &*window.get().0.inner_ref().as_ref().window_ref(),
+                            tauri_window
+                        );
+
+                        // per-item handlers (see `MenuItem::set_handler` and friends) run
+                        // before the window-scoped handler below, same as
+                        // `AppHandle::on_menu_event`.
+                        MenuItemHandlers::dispatch(tauri_window.app_handle(), py, &menu_event.id.0);
+
                         let menu_event: Bound<'_, MenuEvent> = MenuEvent::intern(py, &menu_event.id.0);
 
                         let handler = handler.bind(py);
@@ -375,4 +405,65 @@ impl Webview {
         let window = self.0.inner_ref().window();
         Window::new(window)
     }
+
+    /// Synchronously dispatch a `plugin:pytauri|pyfunc` IPC request against this webview's
+    /// invoke handler and return the raw response bytes, without a real OS window or event loop
+    /// — see [tauri::test::get_ipc_response].
+    ///
+    /// `pyfunc` is the target Python command's name (sent as the `pyfunc` invoke header, the same
+    /// convention [tauri_plugin_pytauri::commands::pyfunc] dispatches on); `body` is the raw,
+    /// already-serialized request payload. Lets a pytest-side test invoke a registered command
+    /// and assert on its response without going through a real webview.
+    ///
+    /// Only meaningful when [crate::tauri_runtime::Runtime] is the mock runtime, i.e. built with
+    /// the `__test` Cargo feature.
+    #[cfg(feature = "__test")]
+    fn mock_ipc_invoke(&self, py: Python<'_>, pyfunc: String, body: Vec<u8>) -> PyResult<Vec<u8>> {
+        use std::collections::HashMap;
+
+        use pyo3::exceptions::PyRuntimeError;
+        use tauri::{
+            ipc::{CallbackFn, InvokeBody, InvokeResponseBody},
+            test::{get_ipc_response, INVOKE_KEY},
+            webview::InvokeRequest,
+        };
+
+        py.allow_threads(|| {
+            let webview = self.0.inner_ref();
+
+            let mut headers = HashMap::new();
+            headers.insert("pyfunc".to_string(), pyfunc);
+            let headers = (&headers)
+                .try_into()
+                .map_err(|e: tauri::http::Error| PyRuntimeError::new_err(e.to_string()))?;
+
+            // see: <https://github.com/tauri-apps/tauri/blob/e3b0260871008e4d213a6036690198ea637d555b/crates/tauri/src/manager/mod.rs#L354>
+            const URL: &str = {
+                if cfg!(windows) {
+                    "http://tauri.localhost/"
+                } else {
+                    "tauri://localhost/"
+                }
+            };
+
+            let resp = get_ipc_response(
+                &*webview,
+                InvokeRequest {
+                    cmd: "plugin:pytauri|pyfunc".into(),
+                    callback: CallbackFn(0),
+                    error: CallbackFn(1),
+                    url: URL.parse().unwrap(),
+                    body: InvokeBody::Raw(body),
+                    headers,
+                    invoke_key: INVOKE_KEY.to_string(),
+                },
+            )
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+            match resp {
+                InvokeResponseBody::Raw(data) => Ok(data),
+                InvokeResponseBody::Json(data) => Ok(data.into_bytes()),
+            }
+        })
+    }
 }
@@ -1,8 +1,13 @@
-use pyo3::prelude::*;
-use pyo3_utils::py_wrapper::{PyWrapper, PyWrapperT0};
+use std::path::PathBuf;
+
+use pyo3::{exceptions::PyNotImplementedError, prelude::*, types::PyString};
+use pyo3_utils::py_wrapper::{PyWrapper, PyWrapperT0, PyWrapperT2};
 use tauri::window;
 
-use crate::tauri_runtime::Runtime;
+use crate::{
+    ext_mod_impl::{tray::PyPhysicalPositionF64, Position, Size},
+    tauri_runtime::Runtime,
+};
 
 type TauriWindow = window::Window<Runtime>;
 
@@ -16,3 +21,134 @@ impl Window {
         Self(PyWrapper::new0(window))
     }
 }
+
+/// tauri's own `CloseRequestApi` is a private type outside the `tauri` crate (see
+/// <https://github.com/tauri-apps/tauri/pull/12701>), so we can't wrap it by name. Instead, we
+/// box up a closure over it (naming a closure never requires naming the types it captures) and
+/// store that in a [PyWrapperT2], so calling [Self::prevent_close] a second time raises
+/// `PyRuntimeError` via [pyo3_utils::py_wrapper::ConsumedError] instead of silently doing
+/// nothing.
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct CloseRequestApi(PyWrapper<PyWrapperT2<Box<dyn FnOnce() + Send>>>);
+
+impl CloseRequestApi {
+    fn new(prevent_close: impl FnOnce() + Send + 'static) -> Self {
+        let prevent_close: Box<dyn FnOnce() + Send> = Box::new(prevent_close);
+        Self(PyWrapper::new2(prevent_close))
+    }
+}
+
+#[pymethods]
+impl CloseRequestApi {
+    /// Ask tauri not to close the window despite this [WindowEvent::CloseRequested].
+    fn prevent_close(&self) -> PyResult<()> {
+        (self.0.try_take_inner()??)();
+        Ok(())
+    }
+}
+
+/// see also: [tauri::DragDropEvent]
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub enum DragDropEvent {
+    Enter {
+        paths: Vec<PathBuf>,
+        position: PyPhysicalPositionF64,
+    },
+    Over {
+        position: PyPhysicalPositionF64,
+    },
+    Drop {
+        paths: Vec<PathBuf>,
+        position: PyPhysicalPositionF64,
+    },
+    Leave(),
+}
+
+impl DragDropEvent {
+    fn from_tauri(py: Python<'_>, event: tauri::DragDropEvent) -> PyResult<Self> {
+        let event = match event {
+            tauri::DragDropEvent::Enter { paths, position } => Self::Enter {
+                paths,
+                position: PyPhysicalPositionF64::from_tauri(py, position)?,
+            },
+            tauri::DragDropEvent::Over { position } => Self::Over {
+                position: PyPhysicalPositionF64::from_tauri(py, position)?,
+            },
+            tauri::DragDropEvent::Drop { paths, position } => Self::Drop {
+                paths,
+                position: PyPhysicalPositionF64::from_tauri(py, position)?,
+            },
+            tauri::DragDropEvent::Leave => Self::Leave(),
+            event => {
+                return Err(PyNotImplementedError::new_err(format!(
+                    "Please make a issue for unimplemented DragDropEvent: {event:?}",
+                )))
+            }
+        };
+        Ok(event)
+    }
+}
+
+/// see also: [tauri::WindowEvent]
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub enum WindowEvent {
+    // use `Py<T>` to avoid creating new obj every time visiting the field,
+    // see: <https://pyo3.rs/v0.23.4/faq.html#pyo3get-clones-my-field>
+    Resized(Py<Size>),
+    Moved(Py<Position>),
+    #[non_exhaustive]
+    CloseRequested {
+        api: Py<CloseRequestApi>,
+    },
+    Destroyed(),
+    Focused(bool),
+    #[non_exhaustive]
+    ScaleFactorChanged {
+        scale_factor: f64,
+        // TODO, XXX, FIXME: `InnerSizeWriter` has no safe way to be exposed to Python yet
+        // (writing through it resizes the window from inside the event callback); leave it out
+        // until there's a concrete need for Python to react to a scale-factor change by resizing.
+        // inner_size_writer: InnerSizeWriter,
+    },
+    ThemeChanged(Py<PyString>),
+    DragDrop(Py<DragDropEvent>),
+}
+
+impl WindowEvent {
+    pub(crate) fn from_tauri(py: Python<'_>, event: tauri::WindowEvent) -> PyResult<Self> {
+        let event = match event {
+            tauri::WindowEvent::Resized(size) => {
+                let size = Size::Physical(size.width, size.height);
+                Self::Resized(size.into_pyobject(py)?.unbind())
+            }
+            tauri::WindowEvent::Moved(position) => {
+                let position = Position::Physical(position.x, position.y);
+                Self::Moved(position.into_pyobject(py)?.unbind())
+            }
+            tauri::WindowEvent::CloseRequested { api, .. } => {
+                let api = Py::new(py, CloseRequestApi::new(move || api.prevent_close()))?;
+                Self::CloseRequested { api }
+            }
+            tauri::WindowEvent::Destroyed => Self::Destroyed(),
+            tauri::WindowEvent::Focused(focused) => Self::Focused(focused),
+            tauri::WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                Self::ScaleFactorChanged { scale_factor }
+            }
+            tauri::WindowEvent::ThemeChanged(theme) => {
+                Self::ThemeChanged(PyString::new(py, &format!("{theme:?}")).unbind())
+            }
+            tauri::WindowEvent::DragDrop(event) => {
+                Self::DragDrop(Py::new(py, DragDropEvent::from_tauri(py, event)?)?)
+            }
+            event => {
+                return Err(PyNotImplementedError::new_err(format!(
+                    "Please make a issue for unimplemented WindowEvent: {event:?}",
+                )))
+            }
+        };
+        Ok(event)
+    }
+}
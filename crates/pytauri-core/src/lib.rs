@@ -20,11 +20,12 @@ pub mod ext_mod {
 
     #[pymodule_export]
     pub use ext_mod_impl::{
-        App, AppHandle, Context, Event, Listener, Manager, Position, Rect, RunEvent, Size,
+        App, AppHandle, Context, Emitter, Event, EventTarget, ExitRequestApi, Listener, Manager,
+        Position, Rect, RunEvent, Size,
     };
 
     pub use ext_mod_impl::{
-        EventId, ImplListener, ImplManager, PyAppHandleExt, PyAppHandleStateError,
+        EventId, ImplEmitter, ImplListener, ImplManager, PyAppHandleExt, PyAppHandleStateError,
         PyAppHandleStateResult,
     };
 
@@ -34,7 +35,9 @@ pub mod ext_mod {
         use super::*;
 
         #[pymodule_export]
-        pub use ext_mod_impl::ipc::{Channel, Invoke, InvokeResolver, JavaScriptChannelId};
+        pub use ext_mod_impl::ipc::{
+            Channel, Invoke, InvokeResolver, JavaScriptChannelId, ScopeObject,
+        };
     }
 
     /// see also: [tauri::webview]
@@ -43,7 +46,7 @@ pub mod ext_mod {
         use super::*;
 
         #[pymodule_export]
-        pub use ext_mod_impl::webview::{Webview, WebviewWindow};
+        pub use ext_mod_impl::webview::{Webview, WebviewEvent, WebviewWindow};
     }
 
     /// see also: [tauri::menu]
@@ -53,12 +56,15 @@ pub mod ext_mod {
 
         #[pymodule_export]
         pub use ext_mod_impl::menu::{
-            AboutMetadata, CheckMenuItem, ContextMenu, IconMenuItem, Menu, MenuItem, NativeIcon,
-            PredefinedMenuItem, Submenu,
+            AboutMetadata, CheckMenuItem, CheckMenuItemBuilder, CheckMenuItemUpdate, ContextMenu,
+            IconMenuItem, IconMenuItemBuilder, IconMenuItemUpdate, Menu, MenuBuilder,
+            MenuEventStream, MenuItem, MenuItemBuilder, NativeIcon, PredefinedMenuItem, Submenu,
+            SubmenuBuilder,
         };
 
         pub use ext_mod_impl::menu::{
-            ImplContextMenu, MenuEvent, MenuID, MenuItemKind, HELP_SUBMENU_ID, WINDOW_SUBMENU_ID,
+            ImplContextMenu, ImplWindow, MenuEvent, MenuID, MenuItemKind, HELP_SUBMENU_ID,
+            WINDOW_SUBMENU_ID,
         };
 
         // TODO: see also <https://github.com/PyO3/pyo3/issues/3900#issue-2153617797> to export `const &str` to python.
@@ -95,7 +101,7 @@ pub mod ext_mod {
         use super::*;
 
         #[pymodule_export]
-        pub use ext_mod_impl::window::Window;
+        pub use ext_mod_impl::window::{CloseRequestApi, DragDropEvent, Window, WindowEvent};
     }
 
     /// see also: [tauri::tray]
@@ -104,7 +110,9 @@ pub mod ext_mod {
         use super::*;
 
         #[pymodule_export]
-        pub use ext_mod_impl::tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconEvent};
+        pub use ext_mod_impl::tray::{
+            MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+        };
 
         pub use ext_mod_impl::tray::TrayIconId;
     }
@@ -108,6 +108,12 @@ pub struct App;
 impl App {
     // `Send` is required for `pyclass`, `tauri::App` is `!Send`,
     // so we have to make it thread local singleton.
+    //
+    // This also stays sound under free-threaded (`Py_GIL_DISABLED`) builds, where multiple
+    // threads really can run Python code at once instead of merely interleaving under the GIL:
+    // `App` is still `frozen`, but every access goes through `try_borrow`/`try_borrow_mut` on a
+    // thread-local `RefCell`, so a thread other than the one `App` was built on just gets a
+    // `PyRuntimeError` instead of racing the real `tauri::App`.
     thread_local! {
         static APP_INST: RefCell<Option<tauri::App>> = const { RefCell::new(None) };
     }
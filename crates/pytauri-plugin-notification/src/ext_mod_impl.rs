@@ -1,9 +1,17 @@
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{Debug, Display};
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3_utils::py_wrapper::{PyWrapper, PyWrapperSemverExt as _, PyWrapperT2};
-use pytauri_core::{ext_mod::ImplManager, tauri_runtime::Runtime};
+use pytauri_core::{
+    ext_mod::ipc::Channel,
+    ext_mod::{EventId, ImplManager},
+    tauri_runtime::Runtime,
+};
+use serde_json::Value as JsonValue;
+use tauri::Listener as _;
 use tauri_plugin_notification::{self as plugin, NotificationExt as _};
 
 #[derive(Debug)]
@@ -31,6 +39,116 @@ impl From<plugin::Error> for PluginError {
     }
 }
 
+/// The repeating cadence for a [NotificationSchedule::every] schedule.
+///
+/// See also: [tauri_plugin_notification::ScheduleEvery].
+#[pyclass(frozen, eq, eq_int)]
+#[derive(PartialEq, Clone, Copy)]
+pub enum ScheduleEvery {
+    Year,
+    Month,
+    TwoWeeks,
+    Week,
+    Day,
+    Weekday,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl From<ScheduleEvery> for plugin::ScheduleEvery {
+    fn from(value: ScheduleEvery) -> Self {
+        match value {
+            ScheduleEvery::Year => plugin::ScheduleEvery::Year,
+            ScheduleEvery::Month => plugin::ScheduleEvery::Month,
+            ScheduleEvery::TwoWeeks => plugin::ScheduleEvery::TwoWeeks,
+            ScheduleEvery::Week => plugin::ScheduleEvery::Week,
+            ScheduleEvery::Day => plugin::ScheduleEvery::Day,
+            ScheduleEvery::Weekday => plugin::ScheduleEvery::Weekday,
+            ScheduleEvery::Hour => plugin::ScheduleEvery::Hour,
+            ScheduleEvery::Minute => plugin::ScheduleEvery::Minute,
+            ScheduleEvery::Second => plugin::ScheduleEvery::Second,
+        }
+    }
+}
+
+/// When a scheduled [NotificationBuilder::show] notification should fire.
+///
+/// See also: [tauri_plugin_notification::Schedule].
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct NotificationSchedule(plugin::Schedule);
+
+#[pymethods]
+impl NotificationSchedule {
+    /// Fire once at a fixed `timestamp` (Unix seconds, UTC). Set `repeating=True` to repeat at
+    /// the same time of day/week/month thereafter.
+    #[staticmethod]
+    #[pyo3(signature = (timestamp, *, repeating = false, allow_while_idle = false))]
+    fn at(timestamp: f64, repeating: bool, allow_while_idle: bool) -> PyResult<Self> {
+        let date = chrono::DateTime::from_timestamp(
+            timestamp.trunc() as i64,
+            (timestamp.fract() * 1e9) as u32,
+        )
+        .ok_or_else(|| PyValueError::new_err("`timestamp` is out of range"))?;
+        Ok(Self(plugin::Schedule::At {
+            date,
+            repeating,
+            allow_while_idle,
+        }))
+    }
+
+    /// Fire on a recurring calendar field match (e.g. every day at a given `hour`/`minute`),
+    /// leaving unset fields unconstrained.
+    #[staticmethod]
+    #[pyo3(signature = (
+        *,
+        year = None,
+        month = None,
+        day = None,
+        weekday = None,
+        hour = None,
+        minute = None,
+        second = None,
+        allow_while_idle = false
+    ))]
+    #[expect(clippy::too_many_arguments)]
+    fn interval(
+        year: Option<i32>,
+        month: Option<i32>,
+        day: Option<i32>,
+        weekday: Option<i32>,
+        hour: Option<i32>,
+        minute: Option<i32>,
+        second: Option<i32>,
+        allow_while_idle: bool,
+    ) -> Self {
+        Self(plugin::Schedule::Interval {
+            interval: plugin::ScheduleInterval {
+                year,
+                month,
+                day,
+                weekday,
+                hour,
+                minute,
+                second,
+            },
+            allow_while_idle,
+        })
+    }
+
+    /// Fire repeatedly every `interval` (e.g. [ScheduleEvery::Day]), `count` times.
+    #[staticmethod]
+    #[pyo3(signature = (interval, count, *, allow_while_idle = false))]
+    fn every(interval: ScheduleEvery, count: i32, allow_while_idle: bool) -> Self {
+        Self(plugin::Schedule::Every {
+            interval: interval.into(),
+            count,
+            allow_while_idle,
+        })
+    }
+}
+
 #[pyclass(frozen)]
 #[non_exhaustive]
 pub struct NotificationBuilder(pub PyWrapper<PyWrapperT2<plugin::NotificationBuilder<Runtime>>>);
@@ -49,6 +167,7 @@ impl NotificationBuilder {
         channel_id = None,
         title = None,
         body = None,
+        schedule = None,
         large_body = None,
         summary = None,
         action_type_id = None,
@@ -59,6 +178,8 @@ impl NotificationBuilder {
         icon = None,
         large_icon = None,
         icon_color = None,
+        attachments = None,
+        extra = None,
         ongoing = false,
         auto_cancel = false,
         silent = false
@@ -71,7 +192,7 @@ impl NotificationBuilder {
         channel_id: Option<String>,
         title: Option<String>,
         body: Option<String>,
-        /* TODO: schedule */
+        schedule: Option<Py<NotificationSchedule>>,
         large_body: Option<String>,
         summary: Option<String>,
         action_type_id: Option<String>,
@@ -82,12 +203,29 @@ impl NotificationBuilder {
         icon: Option<String>,
         large_icon: Option<String>,
         icon_color: Option<String>,
-        /* TODO: attachment */
-        /* TODO: extra */
+        attachments: Option<Vec<(String, String)>>,
+        extra: Option<HashMap<String, JsonValue>>,
         ongoing: bool,
         auto_cancel: bool,
         silent: bool,
     ) -> PyResult<()> {
+        // Scheduling and attachments are backed by the OS-native notification center, which
+        // the Linux (freedesktop) backend doesn't have, see tauri-plugin-notification's
+        // platform support notes.
+        if cfg!(target_os = "linux") {
+            if schedule.is_some() {
+                return Err(PyValueError::new_err(
+                    "`schedule` is not supported on Linux",
+                ));
+            }
+            if attachments.as_ref().is_some_and(|a| !a.is_empty()) {
+                return Err(PyValueError::new_err(
+                    "`attachments` is not supported on Linux",
+                ));
+            }
+        }
+        let schedule = schedule.map(|schedule| schedule.get().0.clone());
+
         // TODO (perf): Do we really need `py.allow_threads` here?
         // I mean, I don't know how long `NotificationBuilder::show` will take,
         // maybe it's short enough?
@@ -106,6 +244,9 @@ impl NotificationBuilder {
             if let Some(body) = body {
                 builder = builder.body(body);
             }
+            if let Some(schedule) = schedule {
+                builder = builder.schedule(schedule);
+            }
             if let Some(large_body) = large_body {
                 builder = builder.large_body(large_body);
             }
@@ -136,6 +277,14 @@ impl NotificationBuilder {
             if let Some(icon_color) = icon_color {
                 builder = builder.icon_color(icon_color);
             }
+            for (attachment_id, url) in attachments.into_iter().flatten() {
+                builder = builder.attachment(attachment_id, url);
+            }
+            for (key, value) in extra.into_iter().flatten() {
+                builder = builder
+                    .extra(key, value)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            }
             if ongoing {
                 builder = builder.ongoing();
             }
@@ -154,6 +303,52 @@ impl NotificationBuilder {
     }
 }
 
+/// The OS permission state for showing notifications.
+///
+/// See also: [tauri_plugin_notification::PermissionState].
+#[pyclass(frozen, eq, eq_int)]
+#[derive(PartialEq, Clone, Copy)]
+pub enum PermissionState {
+    Granted,
+    Denied,
+    Prompt,
+    PromptWithRationale,
+}
+
+impl From<plugin::PermissionState> for PermissionState {
+    fn from(value: plugin::PermissionState) -> Self {
+        match value {
+            plugin::PermissionState::Granted => Self::Granted,
+            plugin::PermissionState::Denied => Self::Denied,
+            plugin::PermissionState::Prompt => Self::Prompt,
+            plugin::PermissionState::PromptWithRationale => Self::PromptWithRationale,
+            // fail closed if the plugin ever adds a state we don't know about yet
+            _ => Self::Denied,
+        }
+    }
+}
+
+/// A currently-displayed notification, as returned by [NotificationExt::active].
+///
+/// See also: [tauri_plugin_notification::ActiveNotification].
+#[pyclass(frozen)]
+#[non_exhaustive]
+pub struct ActiveNotification {
+    #[pyo3(get)]
+    id: i32,
+    #[pyo3(get)]
+    tag: Option<String>,
+}
+
+impl From<plugin::ActiveNotification> for ActiveNotification {
+    fn from(value: plugin::ActiveNotification) -> Self {
+        Self {
+            id: value.id,
+            tag: value.tag,
+        }
+    }
+}
+
 #[pyclass(frozen)]
 #[non_exhaustive]
 pub struct NotificationExt;
@@ -188,4 +383,125 @@ impl NotificationExt {
         }
         notification_ext_method_impl!(slf, builder_impl)
     }
+
+    /// Ask the user to grant the notification permission, returning the resulting state.
+    #[staticmethod]
+    fn request_permission(slf: ImplNotificationExt, py: Python<'_>) -> PyResult<PermissionState> {
+        macro_rules! request_permission_impl {
+            ($wrapper:expr) => {{
+                let py_ref = $wrapper.borrow(py);
+                let guard = py_ref.0.inner_ref_semver()??;
+                let state = guard
+                    .notification()
+                    .request_permission()
+                    .map_err(Into::<PluginError>::into)?;
+                Ok(state.into())
+            }};
+        }
+        notification_ext_method_impl!(slf, request_permission_impl)
+    }
+
+    /// Read the current notification permission state, without prompting the user.
+    #[staticmethod]
+    fn permission_state(slf: ImplNotificationExt, py: Python<'_>) -> PyResult<PermissionState> {
+        macro_rules! permission_state_impl {
+            ($wrapper:expr) => {{
+                let py_ref = $wrapper.borrow(py);
+                let guard = py_ref.0.inner_ref_semver()??;
+                let state = guard
+                    .notification()
+                    .permission_state()
+                    .map_err(Into::<PluginError>::into)?;
+                Ok(state.into())
+            }};
+        }
+        notification_ext_method_impl!(slf, permission_state_impl)
+    }
+
+    /// List the notifications currently shown to the user.
+    #[staticmethod]
+    fn active(slf: ImplNotificationExt, py: Python<'_>) -> PyResult<Vec<ActiveNotification>> {
+        macro_rules! active_impl {
+            ($wrapper:expr) => {{
+                let py_ref = $wrapper.borrow(py);
+                let guard = py_ref.0.inner_ref_semver()??;
+                let active = guard
+                    .notification()
+                    .active()
+                    .map_err(Into::<PluginError>::into)?;
+                Ok(active.into_iter().map(Into::into).collect())
+            }};
+        }
+        notification_ext_method_impl!(slf, active_impl)
+    }
+
+    /// Dismiss currently-shown notifications by `id` (all of them, if `ids` is omitted).
+    #[staticmethod]
+    #[pyo3(signature = (slf, ids=None))]
+    fn remove_active(
+        slf: ImplNotificationExt,
+        py: Python<'_>,
+        ids: Option<Vec<i32>>,
+    ) -> PyResult<()> {
+        macro_rules! remove_active_impl {
+            ($wrapper:expr) => {{
+                let py_ref = $wrapper.borrow(py);
+                let guard = py_ref.0.inner_ref_semver()??;
+                let notifications = ids.map(|ids| {
+                    ids.into_iter()
+                        .map(|id| plugin::ActiveNotification { id, tag: None })
+                        .collect()
+                });
+                guard
+                    .notification()
+                    .remove_active(notifications)
+                    .map_err(Into::<PluginError>::into)?;
+                Ok(())
+            }};
+        }
+        notification_ext_method_impl!(slf, remove_active_impl)
+    }
+
+    /// Forward notification action taps (e.g. pressing an action button registered via
+    /// `NotificationBuilder.show`'s `action_type_id`) to `channel` as they happen, instead of
+    /// only getting a fire-and-forget `show()`.
+    ///
+    /// The event payload (a JSON object describing the action) is decoded and sent through
+    /// `channel` via [Channel::send_json]. Returns the [EventId] so the listener can later be
+    /// removed via `Listener.unlisten`, the same way [tauri::Listener::listen] callers do.
+    #[staticmethod]
+    fn on_action(
+        slf: ImplNotificationExt,
+        py: Python<'_>,
+        channel: Py<Channel>,
+    ) -> PyResult<EventId> {
+        const ACTION_EVENT: &str = "tauri-plugin-notification://action";
+
+        macro_rules! on_action_impl {
+            ($wrapper:expr) => {{
+                let py_ref = $wrapper.borrow(py);
+                let guard = py_ref.0.inner_ref_semver()??;
+                let event_id = guard.listen(ACTION_EVENT, move |event| {
+                    Python::with_gil(|py| {
+                        let payload = event.payload();
+                        let value = match py
+                            .import("json")
+                            .and_then(|json| json.call_method1("loads", (payload,)))
+                        {
+                            Ok(value) => value,
+                            Err(e) => {
+                                e.write_unraisable(py, None);
+                                return;
+                            }
+                        };
+                        if let Err(e) = channel.get().send_json(py, value) {
+                            e.write_unraisable(py, None);
+                        }
+                    })
+                });
+                Ok(event_id)
+            }};
+        }
+        notification_ext_method_impl!(slf, on_action_impl)
+    }
 }
@@ -26,7 +26,10 @@ pub mod notification {
     use super::*;
 
     #[pymodule_export]
-    pub use ext_mod_impl::{NotificationBuilder, NotificationBuilderArgs, NotificationExt};
+    pub use ext_mod_impl::{
+        ActiveNotification, NotificationBuilder, NotificationExt,
+        NotificationSchedule, PermissionState, ScheduleEvery,
+    };
 
     pub use ext_mod_impl::ImplNotificationExt;
 }
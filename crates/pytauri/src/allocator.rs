@@ -0,0 +1,225 @@
+//! Route the embedded Python interpreter's raw/mem/obj allocations through a custom
+//! [GlobalAlloc], instead of CPython's default `malloc`/`free`.
+//!
+//! This is opt-in via the `allocator` Cargo feature (see
+//! [PythonInterpreterBuilder::with_allocator](crate::standalone::PythonInterpreterBuilder::with_allocator));
+//! the default path is untouched unless you call it.
+//!
+//! # NOTE
+//!
+//! Ported from the `jemalloc`-backed `RawAllocator` in [pyembed], generalized over any
+//! [GlobalAlloc] (so e.g. `jemallocator::Jemalloc`, `mimalloc::MiMalloc`, or Rust's own
+//! `std::alloc::System` all work) so this crate doesn't have to vendor a specific allocator
+//! crate.
+//!
+//! [pyembed]: https://crates.io/crates/pyembed
+
+use std::alloc::{GlobalAlloc, Layout};
+use std::ffi::c_void;
+
+use pyo3::ffi as pyffi;
+
+#[repr(C)]
+struct Header {
+    size: usize,
+}
+
+const HEADER_SIZE: usize = std::mem::size_of::<Header>();
+const HEADER_ALIGN: usize = std::mem::align_of::<Header>();
+
+/// Wraps a [GlobalAlloc] so it can back CPython's `malloc`/`calloc`/`realloc`/`free`-shaped
+/// [pyffi::PyMemAllocatorEx]: unlike [GlobalAlloc], that C ABI doesn't pass the original
+/// [Layout] back on `free`/`realloc`, so we prepend a [Header] recording the requested size to
+/// every block and recover it from the returned pointer.
+///
+/// Must stay [Sync]: the `RAW` domain is used before the GIL exists and must keep working
+/// whenever it's not held.
+struct HeaderedAllocator<A> {
+    inner: A,
+}
+
+impl<A: GlobalAlloc> HeaderedAllocator<A> {
+    fn block_layout(size: usize) -> Option<Layout> {
+        let total = size.checked_add(HEADER_SIZE)?;
+        Layout::from_size_align(total, HEADER_ALIGN).ok()
+    }
+
+    unsafe fn malloc(&self, size: usize) -> *mut c_void {
+        let Some(layout) = Self::block_layout(size) else {
+            return std::ptr::null_mut();
+        };
+        let base = unsafe { self.inner.alloc(layout) };
+        if base.is_null() {
+            return std::ptr::null_mut();
+        }
+        unsafe {
+            (base as *mut Header).write(Header { size });
+            base.add(HEADER_SIZE) as *mut c_void
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [Self::malloc]/[Self::calloc]/[Self::realloc] on `self`.
+    unsafe fn header(ptr: *mut c_void) -> (*mut u8, Header) {
+        let base = unsafe { (ptr as *mut u8).sub(HEADER_SIZE) };
+        let header = unsafe { (base as *mut Header).read() };
+        (base, header)
+    }
+
+    unsafe fn calloc(&self, nelem: usize, elsize: usize) -> *mut c_void {
+        let Some(size) = nelem.checked_mul(elsize) else {
+            return std::ptr::null_mut();
+        };
+        let ptr = unsafe { self.malloc(size) };
+        if !ptr.is_null() {
+            unsafe { std::ptr::write_bytes(ptr as *mut u8, 0, size) };
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut c_void, new_size: usize) -> *mut c_void {
+        if ptr.is_null() {
+            return unsafe { self.malloc(new_size) };
+        }
+        let (base, old_header) = unsafe { Self::header(ptr) };
+        let Some(old_layout) = Self::block_layout(old_header.size) else {
+            return std::ptr::null_mut();
+        };
+        let Some(new_layout) = Self::block_layout(new_size) else {
+            return std::ptr::null_mut();
+        };
+        let new_base = unsafe { self.inner.realloc(base, old_layout, new_layout.size()) };
+        if new_base.is_null() {
+            return std::ptr::null_mut();
+        }
+        unsafe {
+            (new_base as *mut Header).write(Header { size: new_size });
+            new_base.add(HEADER_SIZE) as *mut c_void
+        }
+    }
+
+    unsafe fn free(&self, ptr: *mut c_void) {
+        if ptr.is_null() {
+            return;
+        }
+        let (base, header) = unsafe { Self::header(ptr) };
+        if let Some(layout) = Self::block_layout(header.size) {
+            unsafe { self.inner.dealloc(base, layout) };
+        }
+    }
+}
+
+unsafe extern "C" fn trampoline_malloc<A: GlobalAlloc>(
+    ctx: *mut c_void,
+    size: usize,
+) -> *mut c_void {
+    let allocator = unsafe { &*(ctx as *const HeaderedAllocator<A>) };
+    unsafe { allocator.malloc(size) }
+}
+
+unsafe extern "C" fn trampoline_calloc<A: GlobalAlloc>(
+    ctx: *mut c_void,
+    nelem: usize,
+    elsize: usize,
+) -> *mut c_void {
+    let allocator = unsafe { &*(ctx as *const HeaderedAllocator<A>) };
+    unsafe { allocator.calloc(nelem, elsize) }
+}
+
+unsafe extern "C" fn trampoline_realloc<A: GlobalAlloc>(
+    ctx: *mut c_void,
+    ptr: *mut c_void,
+    new_size: usize,
+) -> *mut c_void {
+    let allocator = unsafe { &*(ctx as *const HeaderedAllocator<A>) };
+    unsafe { allocator.realloc(ptr, new_size) }
+}
+
+unsafe extern "C" fn trampoline_free<A: GlobalAlloc>(ctx: *mut c_void, ptr: *mut c_void) {
+    let allocator = unsafe { &*(ctx as *const HeaderedAllocator<A>) };
+    unsafe { allocator.free(ptr) }
+}
+
+/// A named choice of raw allocator for
+/// [PythonInterpreterBuilder::with_raw_allocator](crate::standalone::PythonInterpreterBuilder::with_raw_allocator),
+/// for callers who'd rather flip a Cargo feature than bring their own [GlobalAlloc] and call
+/// [PythonInterpreterBuilder::with_allocator](crate::standalone::PythonInterpreterBuilder::with_allocator)
+/// directly.
+#[non_exhaustive]
+pub enum PythonRawAllocator {
+    /// Leave CPython's default allocator in place; a no-op, included so callers can select a
+    /// variant at runtime (e.g. from a config file) without special-casing "don't install one".
+    System,
+    /// Rust's own global allocator ([std::alloc::System]), routed through the same
+    /// [HeaderedAllocator] wrapper as the other variants.
+    Rust,
+    /// `jemallocator::Jemalloc`. Requires the `jemalloc` Cargo feature.
+    #[cfg(feature = "jemalloc")]
+    Jemalloc,
+    /// `mimalloc::MiMalloc`. Requires the `mimalloc` Cargo feature.
+    #[cfg(feature = "mimalloc")]
+    Mimalloc,
+}
+
+impl PythonRawAllocator {
+    /// Install the chosen allocator; see [install_raw_allocator] for the safety contract, which
+    /// this inherits unchanged ([Self::System] is exempt: it installs nothing).
+    ///
+    /// # Safety
+    ///
+    /// Must be called before [crate::standalone::PythonInterpreterBuilder::build] initializes the
+    /// interpreter, and at most once per process.
+    pub(crate) unsafe fn install(self) {
+        match self {
+            Self::System => {}
+            Self::Rust => unsafe { install_raw_allocator(std::alloc::System) },
+            #[cfg(feature = "jemalloc")]
+            Self::Jemalloc => unsafe { install_raw_allocator(jemallocator::Jemalloc) },
+            #[cfg(feature = "mimalloc")]
+            Self::Mimalloc => unsafe { install_raw_allocator(mimalloc::MiMalloc) },
+        }
+    }
+}
+
+/// Install `allocator` as CPython's allocator for the `RAW`, `MEM`, and `OBJ` domains
+/// (`PyMem_SetAllocator`), after first calling `Py_PreInitialize` so the switch happens before
+/// the interpreter allocates anything through the default allocator.
+///
+/// `allocator` is leaked (not freed): it must remain valid for the life of the process, since
+/// CPython may allocate/free through it until (and even during) interpreter finalization.
+///
+/// # Safety
+///
+/// Must be called before [crate::standalone::PythonInterpreterBuilder::build] initializes the
+/// interpreter, and at most once per process.
+pub(crate) unsafe fn install_raw_allocator<A>(allocator: A)
+where
+    A: GlobalAlloc + Sync + 'static,
+{
+    // `Py_PreInitialize` must run first: it's what lets us override the allocator domains
+    // before `Py_InitializeFromConfig` starts allocating through the default one.
+    unsafe {
+        let mut pre_config: pyffi::PyPreConfig = std::mem::zeroed();
+        pyffi::PyPreConfig_InitPythonConfig(&mut pre_config);
+        let status = pyffi::Py_PreInitialize(&pre_config);
+        debug_assert_eq!(pyffi::PyStatus_Exception(status), 0);
+    }
+
+    let ctx: &'static HeaderedAllocator<A> =
+        Box::leak(Box::new(HeaderedAllocator { inner: allocator }));
+
+    let mut py_allocator = pyffi::PyMemAllocatorEx {
+        ctx: ctx as *const HeaderedAllocator<A> as *mut c_void,
+        malloc: Some(trampoline_malloc::<A>),
+        calloc: Some(trampoline_calloc::<A>),
+        realloc: Some(trampoline_realloc::<A>),
+        free: Some(trampoline_free::<A>),
+    };
+
+    unsafe {
+        pyffi::PyMem_SetAllocator(pyffi::PyMemAllocatorDomain::PYMEM_DOMAIN_RAW, &mut py_allocator);
+        pyffi::PyMem_SetAllocator(pyffi::PyMemAllocatorDomain::PYMEM_DOMAIN_MEM, &mut py_allocator);
+        pyffi::PyMem_SetAllocator(pyffi::PyMemAllocatorDomain::PYMEM_DOMAIN_OBJ, &mut py_allocator);
+    }
+}
@@ -14,11 +14,15 @@
 mod pyembed;
 #[cfg(feature = "standalone")]
 pub mod standalone;
+#[cfg(all(feature = "standalone", feature = "allocator"))]
+pub mod allocator;
+#[cfg(feature = "standalone")]
+pub mod resources;
 
 use pyo3::{
     prelude::*,
     types::{PyCFunction, PyDict, PyModule, PyTuple},
-    wrap_pymodule,
+    wrap_pyfunction, wrap_pymodule,
 };
 use pyo3_utils::py_wrapper::{PyWrapper, PyWrapperT2};
 use pytauri_core::{ext_mod::PyAppHandleExt as _, tauri_runtime::Runtime, utils::TauriError};
@@ -169,6 +173,7 @@ pub fn pymodule_export(
 
     self_module.add_function(builder_factory)?;
     self_module.add_function(context_factory)?;
+    self_module.add_function(wrap_pyfunction!(pyfuture::log::init_logger, self_module)?)?;
     self_module.add_class::<BuilderArgs>()?;
     self_module.add_class::<Builder>()?;
 
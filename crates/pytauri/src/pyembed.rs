@@ -181,6 +181,63 @@ pub(crate) mod utils {
         }
     }
 
+    /// # Safety
+    ///
+    /// You must ensure that you hold a mutable reference to `list`
+    /// (i.e., you must modify it atomically)
+    #[cfg(unix)]
+    pub(crate) unsafe fn append_module_search_path(
+        list: &mut pyffi::PyWideStringList,
+        path: &Path,
+        context: &str,
+    ) -> Result<(), NewInterpreterError> {
+        let value = CString::new(path.as_os_str().as_bytes())
+            .map_err(|_| NewInterpreterError::Simple("cannot convert path to C string"))?;
+
+        // `PyWideStringList_Append` (unlike `PyConfig_SetBytesString`) takes an already-decoded
+        // `wchar_t*` rather than doing the byte->wide decoding itself, so we do it ourselves via
+        // `Py_DecodeLocale` and free the result afterwards: `PyWideStringList_Append` copies it
+        // into the list rather than taking ownership of it.
+        let wide = unsafe { pyffi::Py_DecodeLocale(value.as_ptr(), std::ptr::null_mut()) };
+        if wide.is_null() {
+            return Err(NewInterpreterError::Simple(
+                "Py_DecodeLocale failed to decode path",
+            ));
+        }
+        let status = unsafe { pyffi::PyWideStringList_Append(list, wide) };
+        unsafe { pyffi::PyMem_RawFree(wide as *mut _) };
+
+        if unsafe { pyffi::PyStatus_Exception(status) } != 0 {
+            Err(NewInterpreterError::new_from_pystatus(&status, context))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// # Safety
+    ///
+    /// You must ensure that you hold a mutable reference to `list`
+    /// (i.e., you must modify it atomically)
+    #[cfg(windows)]
+    pub(crate) unsafe fn append_module_search_path(
+        list: &mut pyffi::PyWideStringList,
+        path: &Path,
+        context: &str,
+    ) -> Result<(), NewInterpreterError> {
+        // On Windows `wchar_t` is already UTF-16, so no decoding step is needed (same as
+        // `set_config_string_from_path` above).
+        let mut value: Vec<wchar_t> = path.as_os_str().encode_wide().collect();
+        value.push(0);
+
+        let status = unsafe { pyffi::PyWideStringList_Append(list, value.as_ptr() as *const _) };
+
+        if unsafe { pyffi::PyStatus_Exception(status) } != 0 {
+            Err(NewInterpreterError::new_from_pystatus(&status, context))
+        } else {
+            Ok(())
+        }
+    }
+
     #[cfg(target_family = "unix")]
     pub fn set_argv(
         config: &mut pyffi::PyConfig,
@@ -0,0 +1,237 @@
+//! In-binary Python module resources.
+//!
+//! The only ways to supply modules via [crate::standalone] are
+//! [PythonInterpreterEnv::Venv](crate::standalone::PythonInterpreterEnv::Venv)/
+//! [Standalone](crate::standalone::PythonInterpreterEnv::Standalone)/
+//! [Split](crate::standalone::PythonInterpreterEnv::Split), all of which require a
+//! real on-disk layout. [PythonResources] lets you bundle pure-Python source or precompiled
+//! bytecode directly inside the Rust binary instead (e.g. via `include_bytes!` of a blob you
+//! generate at build time), and installs a `sys.meta_path` finder that imports straight out of
+//! that in-memory index.
+//!
+//! # NOTE
+//!
+//! Modeled after the general approach of PyOxidizer's `_pyoxidizer_importer`.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use pyo3::exceptions::PyImportError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use crate::pyembed::{NewInterpreterError, NewInterpreterResult};
+
+/// The contents of a single Python module registered with [PythonResources].
+///
+/// Build one with [Self::from_source] or [Self::from_bytecode].
+#[derive(Debug, Clone)]
+pub struct ModuleData {
+    source: Option<Cow<'static, [u8]>>,
+    bytecode: Option<Cow<'static, [u8]>>,
+    is_package: bool,
+}
+
+impl ModuleData {
+    /// A module compiled from `source` the first time it's imported.
+    pub fn from_source(source: impl Into<Cow<'static, [u8]>>) -> Self {
+        Self {
+            source: Some(source.into()),
+            bytecode: None,
+            is_package: false,
+        }
+    }
+
+    /// A module unmarshalled from precompiled `bytecode` (e.g. the output of `compile(..., "exec")`
+    /// followed by `marshal.dumps`), instead of being recompiled from source on every import.
+    pub fn from_bytecode(bytecode: impl Into<Cow<'static, [u8]>>) -> Self {
+        Self {
+            source: None,
+            bytecode: Some(bytecode.into()),
+            is_package: false,
+        }
+    }
+
+    /// Mark this module as a package, i.e. it gets `__path__`/`submodule_search_locations` so
+    /// submodules of it can also be registered (e.g. `pkg.sub` alongside `pkg`).
+    pub fn as_package(mut self) -> Self {
+        self.is_package = true;
+        self
+    }
+}
+
+/// Indexes embedded Python modules by their dotted name, to be installed as a `sys.meta_path`
+/// finder via
+/// [PythonInterpreterBuilder::with_resources](crate::standalone::PythonInterpreterBuilder::with_resources).
+#[derive(Debug, Default)]
+pub struct PythonResources {
+    modules: BTreeMap<String, ModuleData>,
+}
+
+impl PythonResources {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `name` (dotted, e.g. `"pkg.mod"`) so it can be imported from `data` instead of
+    /// from disk.
+    pub fn add_module(mut self, name: impl Into<String>, data: ModuleData) -> Self {
+        self.modules.insert(name.into(), data);
+        self
+    }
+
+    /// Decode a batch of modules packed into a single blob, instead of calling [Self::add_module]
+    /// one by one — meant for a build-time step that serializes a whole `site-packages`/`stdlib`
+    /// tree (there's nothing stdlib-specific about [PythonResources]: modules are modules) into
+    /// one `include_bytes!`-able blob.
+    ///
+    /// # Format
+    ///
+    /// A sequence of entries, each:
+    ///
+    /// ```text
+    /// u32 LE  name_len
+    /// [u8; name_len]  dotted module name, UTF-8
+    /// u8      flags: bit 0 = is_package, bit 1 = is_bytecode (otherwise source)
+    /// u32 LE  data_len
+    /// [u8; data_len]  module source, or bytecode as `marshal.dumps` would produce
+    /// ```
+    pub fn from_packed(blob: &'static [u8]) -> NewInterpreterResult<Self> {
+        let mut resources = Self::new();
+        let mut rest = blob;
+
+        while !rest.is_empty() {
+            let name_len = take_u32(&mut rest)? as usize;
+            let name = take(&mut rest, name_len)?;
+            let name = std::str::from_utf8(name)
+                .map_err(|_| NewInterpreterError::Simple("packed module name is not UTF-8"))?;
+
+            let flags = take(&mut rest, 1)?[0];
+            let is_package = flags & 0b01 != 0;
+            let is_bytecode = flags & 0b10 != 0;
+
+            let data_len = take_u32(&mut rest)? as usize;
+            let data = take(&mut rest, data_len)?;
+
+            let mut module = if is_bytecode {
+                ModuleData::from_bytecode(data)
+            } else {
+                ModuleData::from_source(data)
+            };
+            if is_package {
+                module = module.as_package();
+            }
+            resources = resources.add_module(name, module);
+        }
+
+        Ok(resources)
+    }
+
+    /// Install `self` as a finder at `sys.meta_path[0]`.
+    ///
+    /// You usually don't need to call this directly, see
+    /// [PythonInterpreterBuilder::with_resources](crate::standalone::PythonInterpreterBuilder::with_resources).
+    pub(crate) fn install(self, py: Python<'_>) -> PyResult<()> {
+        let finder = Py::new(
+            py,
+            ResourceFinder {
+                modules: self.modules,
+            },
+        )?;
+        py.import("sys")?
+            .getattr("meta_path")?
+            .call_method1("insert", (0, finder))?;
+        Ok(())
+    }
+}
+
+/// Split off and return the first `n` bytes of `*rest`, advancing `*rest` past them.
+fn take(rest: &mut &'static [u8], n: usize) -> NewInterpreterResult<&'static [u8]> {
+    if rest.len() < n {
+        return Err(NewInterpreterError::Simple(
+            "packed resources blob ended unexpectedly",
+        ));
+    }
+    let (taken, remainder) = rest.split_at(n);
+    *rest = remainder;
+    Ok(taken)
+}
+
+/// Like [take], but for a little-endian `u32` length prefix.
+fn take_u32(rest: &mut &'static [u8]) -> NewInterpreterResult<u32> {
+    let bytes = take(rest, 4)?;
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// A `sys.meta_path` finder+loader backed by [PythonResources], installed by
+/// [PythonResources::install].
+///
+/// Implements the `importlib.abc.MetaPathFinder`/`importlib.abc.Loader` protocol (`find_spec`,
+/// `create_module`, `exec_module`) via duck typing, as Python's import system doesn't require actually subclassing
+/// those ABCs.
+#[pyclass(frozen)]
+#[non_exhaustive]
+struct ResourceFinder {
+    modules: BTreeMap<String, ModuleData>,
+}
+
+#[pymethods]
+impl ResourceFinder {
+    // `path`/`target` are part of the `MetaPathFinder.find_spec` protocol but unused by a
+    // flat, dotted-name index like ours.
+    #[pyo3(signature = (fullname, path=None, target=None))]
+    fn find_spec(
+        slf: Py<Self>,
+        py: Python<'_>,
+        fullname: String,
+        path: Option<Bound<'_, PyAny>>,
+        target: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Option<PyObject>> {
+        let _ = (path, target);
+
+        let Some(data) = slf.get().modules.get(&fullname) else {
+            return Ok(None);
+        };
+        let is_package = data.is_package;
+
+        // `origin`/`is_package` are keyword-only on `spec_from_loader`.
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("is_package", is_package)?;
+        let spec = py
+            .import("importlib.util")?
+            .call_method("spec_from_loader", (fullname, slf), Some(&kwargs))?;
+        Ok(Some(spec.unbind()))
+    }
+
+    // `None` selects the import system's default module creation; `importlib` rejects a loader
+    // that defines `exec_module` without also defining `create_module`.
+    fn create_module(&self, spec: Bound<'_, PyAny>) -> Option<PyObject> {
+        let _ = spec;
+        None
+    }
+
+    fn exec_module(&self, py: Python<'_>, module: Bound<'_, PyAny>) -> PyResult<()> {
+        let fullname: String = module.getattr("__name__")?.extract()?;
+        let data = self.modules.get(&fullname).ok_or_else(|| {
+            PyImportError::new_err(format!("no resource registered for module `{fullname}`"))
+        })?;
+
+        let code = if let Some(bytecode) = &data.bytecode {
+            py.import("marshal")?
+                .call_method1("loads", (PyBytes::new(py, bytecode),))?
+        } else if let Some(source) = &data.source {
+            let filename = format!("<embedded:{fullname}>");
+            py.import("builtins")?
+                .call_method1("compile", (PyBytes::new(py, source), filename, "exec"))?
+        } else {
+            return Err(PyImportError::new_err(format!(
+                "resource for module `{fullname}` has neither source nor bytecode"
+            )));
+        };
+
+        let globals = module.getattr("__dict__")?;
+        py.import("builtins")?
+            .call_method1("exec", (code, globals))?;
+        Ok(())
+    }
+}
@@ -14,10 +14,11 @@
 
 use std::{
     borrow::Cow,
-    env::{args_os, current_exe},
-    ffi::OsString,
+    env::{args_os, current_exe, var},
+    ffi::{CString, OsString},
     ops::Drop,
-    path::Path,
+    path::{Path, PathBuf},
+    process::Command as StdCommand,
 };
 
 use pyo3::{
@@ -26,28 +27,51 @@ use pyo3::{
     types::{PyDict, PyModule},
 };
 
+#[cfg(feature = "allocator")]
+use crate::allocator::PythonRawAllocator;
 use crate::pyembed::utils;
 pub use crate::pyembed::{NewInterpreterError, NewInterpreterResult};
+use crate::resources::PythonResources;
 
+/// How [PythonInterpreterConfig::new] initializes the underlying `PyConfig`, see:
+/// <https://docs.python.org/3/c-api/init_config.html#isolated-configuration>
 #[non_exhaustive]
-enum PyConfigProfile {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PythonInterpreterConfigProfile {
+    /// `PyConfig_InitPythonConfig`: behaves like the regular `python3` CLI (reads env vars,
+    /// parses `sys.argv`, imports `site`, ...), the profile [PythonInterpreterBuilder] itself
+    /// uses.
     Python,
-    #[expect(dead_code)]
+    /// `PyConfig_InitIsolatedConfig`: isolates the interpreter from the user's environment (no
+    /// env vars, no user `site-packages`, no `sys.path` entry for the script's directory), the
+    /// profile embedders typically want when they drive [PythonInterpreterConfig] directly.
     Isolated,
 }
 
+/// A lower-level, end-to-end builder over `pyffi::PyConfig`, for embedders that need more
+/// control over interpreter startup than [PythonInterpreterBuilder] offers (e.g. isolated
+/// initialization, extra `sys.path` entries, toggling `site`/bytecode writing).
+///
+/// Dropping a not-yet-[Self::init]-ed config calls `PyConfig_Clear`, so a partially configured
+/// instance never leaks.
+///
 /// see: <https://docs.python.org/3/c-api/init_config.html#c.PyConfig>
-struct PyConfig(pyffi::PyConfig);
+#[non_exhaustive]
+pub struct PythonInterpreterConfig(pyffi::PyConfig);
 
 // ref: <https://github.com/indygreg/PyOxidizer/blob/1ceca8664c71f39e849ce4873e00d821504b32bd/pyembed/src/interpreter_config.rs#L252-L619>
-impl PyConfig {
-    pub fn new(profile: PyConfigProfile) -> Self {
+impl PythonInterpreterConfig {
+    pub fn new(profile: PythonInterpreterConfigProfile) -> Self {
         let mut config: pyffi::PyConfig = unsafe { std::mem::zeroed() };
 
         unsafe {
             match profile {
-                PyConfigProfile::Isolated => pyffi::PyConfig_InitIsolatedConfig(&mut config),
-                PyConfigProfile::Python => pyffi::PyConfig_InitPythonConfig(&mut config),
+                PythonInterpreterConfigProfile::Isolated => {
+                    pyffi::PyConfig_InitIsolatedConfig(&mut config)
+                }
+                PythonInterpreterConfigProfile::Python => {
+                    pyffi::PyConfig_InitPythonConfig(&mut config)
+                }
             }
         }
 
@@ -58,14 +82,12 @@ impl PyConfig {
         unsafe { utils::set_config_string_from_path(&self.0, &self.0.home, home, "setting home") }
     }
 
-    #[expect(dead_code)]
     pub fn set_prefix(&mut self, prefix: &Path) -> NewInterpreterResult<()> {
         unsafe {
             utils::set_config_string_from_path(&self.0, &self.0.prefix, prefix, "setting prefix")
         }
     }
 
-    #[expect(dead_code)]
     pub fn set_base_prefix(&mut self, base_prefix: &Path) -> NewInterpreterResult<()> {
         unsafe {
             utils::set_config_string_from_path(
@@ -77,7 +99,6 @@ impl PyConfig {
         }
     }
 
-    #[expect(dead_code)]
     pub fn set_exec_prefix(&mut self, exec_prefix: &Path) -> NewInterpreterResult<()> {
         unsafe {
             utils::set_config_string_from_path(
@@ -89,7 +110,6 @@ impl PyConfig {
         }
     }
 
-    #[expect(dead_code)]
     pub fn set_base_exec_prefix(&mut self, base_exec_prefix: &Path) -> NewInterpreterResult<()> {
         unsafe {
             utils::set_config_string_from_path(
@@ -131,6 +151,38 @@ impl PyConfig {
         self.0.parse_argv = if parse_argv { 1 } else { 0 };
     }
 
+    /// Whether to `import site` on startup, i.e. process `.pth` files and set up
+    /// `site-packages` on `sys.path`. On by default under both [PythonInterpreterConfigProfile]s.
+    pub fn set_site_import(&mut self, site_import: bool) {
+        self.0.site_import = if site_import { 1 } else { 0 };
+    }
+
+    /// Whether to add the user site-packages directory to `sys.path`.
+    pub fn set_user_site_directory(&mut self, user_site_directory: bool) {
+        self.0.user_site_directory = if user_site_directory { 1 } else { 0 };
+    }
+
+    /// Whether importing a module can write its compiled `.pyc` back to disk.
+    pub fn set_write_bytecode(&mut self, write_bytecode: bool) {
+        self.0.write_bytecode = if write_bytecode { 1 } else { 0 };
+    }
+
+    /// Append `path` to `sys.path`.
+    ///
+    /// Unlike the other `set_*` methods, this doesn't replace a single field: it implies
+    /// `module_search_paths_set = 1` (telling CPython to use exactly the paths pushed here
+    /// instead of calculating its own) and may be called more than once to add several entries.
+    pub fn append_module_search_path(&mut self, path: &Path) -> NewInterpreterResult<()> {
+        self.0.module_search_paths_set = 1;
+        unsafe {
+            utils::append_module_search_path(
+                &mut self.0.module_search_paths,
+                path,
+                "appending module search path",
+            )
+        }
+    }
+
     pub fn set_run_command(&mut self, run_command: &str) -> NewInterpreterResult<()> {
         unsafe {
             utils::set_config_string_from_str(
@@ -202,7 +254,7 @@ impl PyConfig {
 }
 
 /// Clear the `PyConfig` to release memory.
-impl Drop for PyConfig {
+impl Drop for PythonInterpreterConfig {
     fn drop(&mut self) {
         unsafe {
             pyffi::PyConfig_Clear(&mut self.0);
@@ -234,15 +286,67 @@ pub fn is_forking() -> bool {
     }
 }
 
+/// The `multiprocessing` start method to use for worker processes, see
+/// [PythonInterpreterBuilder::multiprocessing_start_method].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiprocessingStartMethod {
+    Spawn,
+    Fork,
+    ForkServer,
+}
+
+impl MultiprocessingStartMethod {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Spawn => "spawn",
+            Self::Fork => "fork",
+            Self::ForkServer => "forkserver",
+        }
+    }
+
+    /// The method [PythonInterpreterBuilder::build] used before this was configurable:
+    /// `Spawn` on Windows, `Fork` on Unix.
+    fn default_for_platform() -> Self {
+        if cfg!(windows) {
+            Self::Spawn
+        } else {
+            Self::Fork
+        }
+    }
+
+    /// Whether the current process looks like a worker spawned under this start method.
+    ///
+    /// Only `Spawn` workers are detectable this way: like [is_forking], they're identified by the
+    /// `--multiprocessing-fork [key=value] ...` argv token. A `ForkServer` worker is forked from a
+    /// server process that is started with `-c` rather than that token, and `Fork` workers
+    /// inherit the parent's memory directly instead of re-executing, so neither is
+    /// distinguishable from the parent by argv: this always returns `false` for `Fork`, and for
+    /// `ForkServer` it returns [is_forking]'s answer, which is `false` in those processes.
+    pub fn is_worker_process(self) -> bool {
+        match self {
+            Self::Spawn | Self::ForkServer => is_forking(),
+            Self::Fork => false,
+        }
+    }
+}
+
 fn _post_init_pyi(
     py: Python<'_>,
     current_exe: &Path,
     ext_mod: Py<PyModule>,
+    multiprocessing_start_method: MultiprocessingStartMethod,
+    multiprocessing_executable: &Path,
 ) -> NewInterpreterResult<()> {
     let script = || {
         let locals = PyDict::new(py);
         locals.set_item("CURRENT_EXE", current_exe)?;
         locals.set_item("EXT_MOD", ext_mod)?;
+        locals.set_item(
+            "MULTIPROCESSING_START_METHOD",
+            multiprocessing_start_method.as_str(),
+        )?;
+        locals.set_item("MULTIPROCESSING_EXECUTABLE", multiprocessing_executable)?;
 
         // TODO, PERF: compile into python bytecode.
         // see: <https://users.rust-lang.org/t/why-calling-python-from-rust-is-faster-than-python/39789/13>
@@ -314,13 +418,81 @@ pub enum PythonInterpreterEnv<'a> {
     /// ...
     /// ```
     Standalone(Cow<'a, Path>),
+    /// A [Standalone]-like distribution whose `libpython`/stdlib has been moved out of the
+    /// distribution's own `root` directory, e.g. by AppImage packaging, which relocates
+    /// `libpython` to `${APPDIR}/usr/lib/` instead of leaving it
+    /// [next to the standard library](Self::Standalone).
+    ///
+    /// Unlike [Standalone], CPython can't guess `prefix`/`exec_prefix` from `executable` here, so
+    /// you must provide them explicitly.
+    ///
+    /// [Standalone]: Self::Standalone
+    Split {
+        /// The python executable path, e.g. `${APPDIR}/usr/lib/{your-app-name}/python3`.
+        executable: Cow<'a, Path>,
+        /// The directory containing the standard library, used for both `prefix` and
+        /// `base_prefix`.
+        stdlib_prefix: Cow<'a, Path>,
+        /// The directory containing platform-specific standard library modules, used for both
+        /// `exec_prefix` and `base_exec_prefix`.
+        ///
+        /// On most layouts (including `python-build-standalone`), this is the same directory as
+        /// `stdlib_prefix`.
+        exec_prefix: Cow<'a, Path>,
+    },
 }
 
 impl PythonInterpreterEnv<'_> {
+    /// The python executable path this environment implies, e.g. `${root}/bin/python3` for
+    /// [Self::Standalone] on Unix.
+    ///
+    /// NOTE: the `windows`/`unix` branching below is resolved against the *build target*, not
+    /// the host that's compiling it — `cfg!` (like every `#[cfg(...)]`) is always evaluated for
+    /// the target triple, so this already does the right thing under e.g.
+    /// `cargo build --target x86_64-pc-windows-msvc` from a Linux host, with no extra plumbing
+    /// of `CARGO_CFG_TARGET_OS` required.
+    fn executable_path(&self) -> Cow<'_, Path> {
+        match self {
+            PythonInterpreterEnv::Venv(dir) => {
+                if cfg!(windows) {
+                    Cow::Owned(dir.join(r"Scripts\python.exe"))
+                } else {
+                    Cow::Owned(dir.join("bin/python3"))
+                }
+            }
+            PythonInterpreterEnv::Standalone(dir) => {
+                if cfg!(windows) {
+                    Cow::Owned(dir.join("python.exe"))
+                } else {
+                    Cow::Owned(dir.join("bin/python3"))
+                }
+            }
+            PythonInterpreterEnv::Split { executable, .. } => Cow::Borrowed(executable.as_ref()),
+        }
+    }
+
+    /// Make sure [Self::executable_path] actually exists, so a mismatch between the build
+    /// target and whatever was bundled alongside it (e.g. a `python-build-standalone` for the
+    /// wrong OS/arch got packaged by mistake) surfaces as a clear [NewInterpreterError] here,
+    /// instead of a much more opaque failure later out of `Py_InitializeFromConfig`.
+    fn validate_executable(&self) -> NewInterpreterResult<()> {
+        let executable = self.executable_path();
+        if !executable.is_file() {
+            return Err(NewInterpreterError::Dynamic(format!(
+                "no python executable found at `{}` (build target OS is `{}`); make sure the \
+                 embedded/virtual environment bundled next to this binary was built for that \
+                 target, not for the host that compiled it",
+                executable.display(),
+                std::env::consts::OS,
+            )));
+        }
+        Ok(())
+    }
+
     // ref:
     // - <https://docs.python.org/3.13/c-api/init_config.html#python-path-configuration>
     // - <https://github.com/python/cpython/blob/3.13/Modules/getpath.py>
-    fn set_path_for_config(self, config: &mut PyConfig) -> NewInterpreterResult<()> {
+    fn set_path_for_config(self, config: &mut PythonInterpreterConfig) -> NewInterpreterResult<()> {
         // necessary, because:
         // 1. make sure that `sys.executable` is actually the python executable
         // 2. python can calculate other path such as `PyConfig.prefix`, from `PyConfig.executable`.
@@ -367,6 +539,21 @@ impl PythonInterpreterEnv<'_> {
 
                 home = Some(dir);
             }
+            // `home` doesn't apply here: CPython can't guess `prefix`/`exec_prefix` once
+            // `libpython` has been moved away from the distribution's own `root`, so we set them
+            // explicitly instead.
+            PythonInterpreterEnv::Split {
+                executable,
+                stdlib_prefix,
+                exec_prefix,
+            } => {
+                config.set_executable(&executable)?;
+                config.set_prefix(&stdlib_prefix)?;
+                config.set_base_prefix(&stdlib_prefix)?;
+                config.set_exec_prefix(&exec_prefix)?;
+                config.set_base_exec_prefix(&exec_prefix)?;
+                return Ok(());
+            }
         }
 
         config.set_executable(&executable)?;
@@ -395,6 +582,158 @@ pub enum PythonScript<'a> {
     REPL,
 }
 
+impl PythonScript<'_> {
+    /// Clone the borrowed data out so the result no longer depends on `'a`, for stashing on
+    /// [PythonInterpreter] (which [Self::run_and_report] needs to replicate `Py_RunMain`'s
+    /// dispatch after the builder that borrowed `self` is gone).
+    fn into_static(self) -> PythonScript<'static> {
+        match self {
+            PythonScript::File(path) => PythonScript::File(Cow::Owned(path.into_owned())),
+            PythonScript::Module(module) => PythonScript::Module(Cow::Owned(module.into_owned())),
+            PythonScript::Code(code) => PythonScript::Code(Cow::Owned(code.into_owned())),
+            PythonScript::REPL => PythonScript::REPL,
+        }
+    }
+}
+
+/// The outcome of [PythonInterpreter::run_and_report].
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum PythonRunResult {
+    /// The script ran to completion without raising.
+    Ok,
+    /// The script raised an uncaught exception (other than [SystemExit]), formatted as a
+    /// traceback by Python's `traceback` module.
+    ///
+    /// [SystemExit]: https://docs.python.org/3/library/exceptions.html#SystemExit
+    Err(String),
+    /// The script called `sys.exit(code)`, or [SystemExit] otherwise propagated out uncaught;
+    /// `code` is the process exit code (`0` if `SystemExit.code` was `None` or absent, `1` if it
+    /// was set but not an integer).
+    Exit(i32),
+}
+
+/// Structured context accompanying a [PythonCrashReport], so a [PythonErrorSink] can include it
+/// in a log line, JSON report, or dialog without re-deriving it from the
+/// [PythonInterpreterBuilder].
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct PythonCrashContext {
+    /// The directory backing the [PythonInterpreterEnv] the interpreter was built with
+    /// ([PythonInterpreterEnv::Venv]/[PythonInterpreterEnv::Standalone]'s `root`, or
+    /// [PythonInterpreterEnv::Split]'s `stdlib_prefix`).
+    pub resource_dir: PathBuf,
+    /// Which [PythonScript] variant was run: `"file"`/`"module"`/`"code"`/`"repl"`.
+    pub interpreter_mode: &'static str,
+    /// The embedding app's version, if supplied via
+    /// [PythonInterpreterBuilder::with_app_version] (e.g. from the generated
+    /// `tauri::Context::config().version`).
+    pub app_version: Option<String>,
+}
+
+/// Handed to a [PythonErrorSink] when [PythonInterpreter::run_and_report] observes an uncaught
+/// Python exception.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct PythonCrashReport {
+    /// The formatted traceback, the same string carried by [PythonRunResult::Err].
+    pub traceback: String,
+    /// See [PythonCrashContext].
+    pub context: PythonCrashContext,
+}
+
+/// A pluggable destination for a [PythonCrashReport], registered via
+/// [PythonInterpreterBuilder::with_error_sink] instead of being hardcoded into
+/// [PythonInterpreter::run_and_report].
+///
+/// Implemented for any `Fn(&PythonCrashReport) + Send + Sync`, so a Rust closure works directly —
+/// e.g. write a JSON report, or forward to an in-app dialog via
+/// [PythonRunResult::report_native_dialog]. To route to a Python-side sink (e.g. the app's own
+/// logging framework), capture a `Py<PyAny>` callable and invoke it with
+/// [Python::with_gil] from inside the closure.
+pub trait PythonErrorSink: Send + Sync {
+    fn report(&self, report: &PythonCrashReport);
+}
+
+impl<F> PythonErrorSink for F
+where
+    F: Fn(&PythonCrashReport) + Send + Sync,
+{
+    fn report(&self, report: &PythonCrashReport) {
+        self(report)
+    }
+}
+
+/// The default [PythonErrorSink]: writes the traceback to a file, replacing whatever it held
+/// before. This is the behavior
+/// [PythonInterpreterBuilder] used to hardcode; it's now just the default, swap it out via
+/// [PythonInterpreterBuilder::with_error_sink].
+#[derive(Debug, Clone)]
+pub struct FileErrorSink {
+    pub log_path: PathBuf,
+}
+
+impl FileErrorSink {
+    pub fn new(log_path: impl Into<PathBuf>) -> Self {
+        Self {
+            log_path: log_path.into(),
+        }
+    }
+}
+
+impl PythonErrorSink for FileErrorSink {
+    fn report(&self, report: &PythonCrashReport) {
+        if let Err(e) = std::fs::write(&self.log_path, &report.traceback) {
+            // Nowhere else left to surface this, so fall back to stderr.
+            eprintln!(
+                "failed to write traceback to `{}`: {e}\n{}",
+                self.log_path.display(),
+                report.traceback
+            );
+        }
+    }
+}
+
+impl PythonRunResult {
+    /// If `self` is [Self::Err], show its traceback in a blocking native message dialog, e.g. for
+    /// a windowed app (`windows_subsystem = "windows"`) that has no console to print it to.
+    ///
+    /// Falls back to writing the traceback to `log_path` (replacing its previous contents) if the
+    /// dialog can't be shown (e.g. no
+    /// display server, or the `rfd` backend itself errors) — this is meant to replace a bare
+    /// "write everything to `error.log`" fallback, not remove it, so an uncaught exception is
+    /// never silently lost even in that worst case.
+    ///
+    /// No-op for [Self::Ok]/[Self::Exit]. Requires the `native-dialog` Cargo feature.
+    #[cfg(feature = "native-dialog")]
+    pub fn report_native_dialog(&self, log_path: &Path) {
+        let Self::Err(traceback) = self else {
+            return;
+        };
+
+        let shown = std::panic::catch_unwind(|| {
+            rfd::MessageDialog::new()
+                .set_level(rfd::MessageLevel::Error)
+                .set_title("Unhandled Python exception")
+                .set_description(traceback)
+                .set_buttons(rfd::MessageButtons::Ok)
+                .show();
+        })
+        .is_ok();
+
+        if !shown {
+            if let Err(e) = std::fs::write(log_path, traceback) {
+                // Last resort: both the dialog and the log write failed. There's nowhere else
+                // left to surface this, so fall back to stderr.
+                eprintln!(
+                    "failed to write traceback to `{}`: {e}\n{traceback}",
+                    log_path.display()
+                );
+            }
+        }
+    }
+}
+
 /// Build a Python interpreter for your script.
 ///
 /// # Behavior
@@ -404,13 +743,16 @@ pub enum PythonScript<'a> {
 /// - Set `PyConfig.program_name` to `std::env::current_exe()`.
 /// - Set `sys.executable` to the actual python interpreter executable path.
 /// - Set `PyConfig.home` to [PythonInterpreterEnv::Standalone::0].
-/// - Set `sys.argv` to `std::env::args_os()`.
+/// - Set `sys.argv` to `std::env::args_os()` (unless overridden via [Self::with_argv]).
 /// - Set `PyConfig.parse_argv` to `false`.
 /// - Set `sys.frozen` to `True`.
 /// - Call `multiprocessing.set_start_method` with
 ///     - windows: `spawn`
 ///     - unix: `fork`
+///
+///   (unless overridden via [Self::multiprocessing_start_method])
 /// - Call `multiprocessing.set_executable` with `std::env::current_exe()`
+///   (unless overridden via [Self::multiprocessing_executable])
 #[non_exhaustive]
 pub struct PythonInterpreterBuilder<'a, M>
 where
@@ -419,6 +761,14 @@ where
     env: PythonInterpreterEnv<'a>,
     script: PythonScript<'a>,
     ext_mod: M,
+    #[cfg(feature = "allocator")]
+    allocator: Option<Box<dyn FnOnce() + 'a>>,
+    resources: Option<PythonResources>,
+    multiprocessing_start_method: Option<MultiprocessingStartMethod>,
+    multiprocessing_executable: Option<PathBuf>,
+    argv: Option<Vec<OsString>>,
+    error_sink: Option<Box<dyn PythonErrorSink>>,
+    app_version: Option<String>,
 }
 
 impl<'a, M> PythonInterpreterBuilder<'a, M>
@@ -447,9 +797,97 @@ where
             env,
             script,
             ext_mod,
+            #[cfg(feature = "allocator")]
+            allocator: None,
+            resources: None,
+            multiprocessing_start_method: None,
+            multiprocessing_executable: None,
+            argv: None,
+            error_sink: None,
+            app_version: None,
         }
     }
 
+    /// Install `resources` as a `sys.meta_path` finder, so modules it indexes can be imported
+    /// straight out of the binary instead of requiring an on-disk `stdlib`/`site-packages` layout.
+    ///
+    /// See [resources](crate::resources) for details.
+    pub fn with_resources(mut self, resources: PythonResources) -> Self {
+        self.resources = Some(resources);
+        self
+    }
+
+    /// Use `method` as the `multiprocessing` start method, instead of the platform default
+    /// (`spawn` on Windows, `fork` on Unix).
+    pub fn multiprocessing_start_method(mut self, method: MultiprocessingStartMethod) -> Self {
+        self.multiprocessing_start_method = Some(method);
+        self
+    }
+
+    /// Spawn `multiprocessing` workers from `executable`, instead of the current executable.
+    ///
+    /// Useful when the worker entry point differs from the GUI launcher, e.g. a separate
+    /// headless binary.
+    pub fn multiprocessing_executable(mut self, executable: PathBuf) -> Self {
+        self.multiprocessing_executable = Some(executable);
+        self
+    }
+
+    /// Set `sys.argv` to `argv`, instead of the default [std::env::args_os] (which includes the
+    /// running executable's own path as `argv[0]`, the same as CPython's own CLI).
+    ///
+    /// Useful when the process's real `argv` isn't what your [PythonScript] entrypoint should
+    /// see, e.g. a launcher that strips/rewrites its own flags before handing the rest to Python.
+    pub fn with_argv(mut self, argv: Vec<OsString>) -> Self {
+        self.argv = Some(argv);
+        self
+    }
+
+    /// Register `sink` as where [PythonInterpreter::run_and_report] sends a [PythonCrashReport]
+    /// on an uncaught Python exception, instead of the default [FileErrorSink].
+    pub fn with_error_sink(mut self, sink: impl PythonErrorSink + 'static) -> Self {
+        self.error_sink = Some(Box::new(sink));
+        self
+    }
+
+    /// Record `version` on [PythonCrashContext::app_version], so a [PythonErrorSink] can include
+    /// it in a report without the caller threading it through separately. Typically the
+    /// embedding app's generated `tauri::Context::config().version`.
+    pub fn with_app_version(mut self, version: impl Into<String>) -> Self {
+        self.app_version = Some(version.into());
+        self
+    }
+
+    /// Route the embedded interpreter's raw/mem/obj allocations through `allocator` (e.g.
+    /// `jemallocator::Jemalloc`, `mimalloc::MiMalloc`), instead of CPython's default allocator.
+    ///
+    /// Requires the `allocator` Cargo feature.
+    #[cfg(feature = "allocator")]
+    pub fn with_allocator<A>(mut self, allocator: A) -> Self
+    where
+        A: std::alloc::GlobalAlloc + Sync + 'static,
+    {
+        self.allocator = Some(Box::new(move || {
+            // SAFETY: called below, before the interpreter is initialized, and only once.
+            unsafe { crate::allocator::install_raw_allocator(allocator) }
+        }));
+        self
+    }
+
+    /// Like [Self::with_allocator], but choosing from the built-in [PythonRawAllocator] variants
+    /// instead of supplying your own [std::alloc::GlobalAlloc].
+    ///
+    /// Requires the `allocator` Cargo feature (and, for [PythonRawAllocator::Jemalloc]/
+    /// [PythonRawAllocator::Mimalloc], the matching `jemalloc`/`mimalloc` feature).
+    #[cfg(feature = "allocator")]
+    pub fn with_raw_allocator(mut self, allocator: PythonRawAllocator) -> Self {
+        self.allocator = Some(Box::new(move || {
+            // SAFETY: called below, before the interpreter is initialized, and only once.
+            unsafe { allocator.install() }
+        }));
+        self
+    }
+
     /// Build the Python interpreter.
     ///
     /// After calling this function, the Python interpreter is initialized.
@@ -457,7 +895,7 @@ where
     ///
     /// NOTE: you can only build only one Python interpreter per process,
     /// or you will get a [NewInterpreterError].
-    pub fn build(self) -> NewInterpreterResult<PythonInterpreter> {
+    pub fn build(mut self) -> NewInterpreterResult<PythonInterpreter> {
         let current_exe = current_exe().map_err(|e| {
             NewInterpreterError::Dynamic(format!(
                 "failed to get the current executable path: {}",
@@ -465,19 +903,53 @@ where
             ))
         })?;
 
-        let mut config = PyConfig::new(PyConfigProfile::Python);
+        #[cfg(feature = "allocator")]
+        if let Some(install) = self.allocator {
+            install();
+        }
+
+        let mut config = PythonInterpreterConfig::new(PythonInterpreterConfigProfile::Python);
 
         // 👇 Init config ref:
         // - <https://github.com/python/cpython/blob/3.13/Modules/getpath.py>
         // - <https://docs.python.org/3.13/c-api/init_config.html#python-path-configuration>
         // - <https://docs.python.org/3.13/c-api/intro.html#embedding-python>
 
+        // captured before `self.env`/`self.script` are consumed below, so a later crash report
+        // can still describe what environment/mode the interpreter was built with.
+        let resource_dir = match &self.env {
+            PythonInterpreterEnv::Venv(dir) | PythonInterpreterEnv::Standalone(dir) => {
+                dir.as_ref().to_path_buf()
+            }
+            PythonInterpreterEnv::Split { stdlib_prefix, .. } => stdlib_prefix.as_ref().to_path_buf(),
+        };
+        let interpreter_mode = match &self.script {
+            PythonScript::File(_) => "file",
+            PythonScript::Module(_) => "module",
+            PythonScript::Code(_) => "code",
+            PythonScript::REPL => "repl",
+        };
+        let crash_context = PythonCrashContext {
+            resource_dir,
+            interpreter_mode,
+            app_version: self.app_version.take(),
+        };
+        let error_sink = self
+            .error_sink
+            .take()
+            .unwrap_or_else(|| Box::new(FileErrorSink::new("error.log")));
+
         // in fact, unnecessary, python will get it from `argv[0]`
         config.set_program_name(&current_exe)?;
+        // catch a wrong-target embedded/virtual environment early, with a clear error, instead
+        // of letting `Py_InitializeFromConfig` below fail opaquely (or worse).
+        self.env.validate_executable()?;
         // necessary for finding the standard library and installed libraries
         self.env.set_path_for_config(&mut config)?;
-        // necessary for `multiprocessing`
-        config.set_argv(&args_os().collect::<Vec<_>>())?;
+        // necessary for `multiprocessing`, and so CLI flags the process was launched with (e.g.
+        // `myapp --open file.json`) reach the Python entrypoint via `sys.argv`.
+        let argv = self.argv.take().unwrap_or_else(|| args_os().collect());
+        config.set_argv(&argv)?;
         // `parse_argv=false` is necessary, because python only accepts following argv pattern:
         //
         // ```shell
@@ -488,23 +960,48 @@ where
         // This will prevent us from using libraries like `clap` to parse command line arguments
         config.set_parse_argv(false);
 
-        match self.script {
+        match &self.script {
             PythonScript::File(path) => {
-                config.set_run_filename(&path)?;
+                config.set_run_filename(path)?;
             }
             PythonScript::Module(module) => {
-                config.set_run_module(&module)?;
+                config.set_run_module(module)?;
             }
             PythonScript::Code(code) => {
-                config.set_run_command(&code)?;
+                config.set_run_command(code)?;
             }
             PythonScript::REPL => {
                 // if we don't set any of the above, `Py_RunMain` will run the REPL
             }
         }
 
-        let interpreter = PythonInterpreter::new(config)?;
-        interpreter.with_gil(|py| _post_init_pyi(py, &current_exe, (self.ext_mod)(py)))?;
+        let multiprocessing_start_method = self
+            .multiprocessing_start_method
+            .unwrap_or_else(MultiprocessingStartMethod::default_for_platform);
+        let multiprocessing_executable = self
+            .multiprocessing_executable
+            .clone()
+            .unwrap_or_else(|| current_exe.clone());
+
+        let interpreter =
+            PythonInterpreter::new(config, self.script.into_static(), crash_context, error_sink)?;
+        interpreter.with_gil(|py| {
+            _post_init_pyi(
+                py,
+                &current_exe,
+                (self.ext_mod)(py),
+                multiprocessing_start_method,
+                &multiprocessing_executable,
+            )?;
+            // Installed right after core init, same as everything else in `_post_init_pyi`, so
+            // it's in place before any application code runs its first import.
+            if let Some(resources) = self.resources {
+                resources
+                    .install(py)
+                    .map_err(|e| NewInterpreterError::new_from_pyerr(py, e, "installing resources"))?;
+            }
+            Ok(())
+        })?;
 
         Ok(interpreter)
     }
@@ -526,15 +1023,28 @@ where
 /// it is recommended to always go through a method on an [PythonInterpreter]
 /// instance in order to interact with the Python interpreter.
 #[non_exhaustive]
-pub struct PythonInterpreter {}
+pub struct PythonInterpreter {
+    script: PythonScript<'static>,
+    crash_context: PythonCrashContext,
+    error_sink: Box<dyn PythonErrorSink>,
+}
 
 impl PythonInterpreter {
-    fn new(config: PyConfig) -> NewInterpreterResult<Self> {
-        // [PyConfig::init()] need make sure if it failed, the interpreter is not initialized.
+    fn new(
+        config: PythonInterpreterConfig,
+        script: PythonScript<'static>,
+        crash_context: PythonCrashContext,
+        error_sink: Box<dyn PythonErrorSink>,
+    ) -> NewInterpreterResult<Self> {
+        // [PythonInterpreterConfig::init()] need make sure if it failed, the interpreter is not initialized.
         // So we can just return here and dont need finalize the interpreter.
         config.init()?;
 
-        let slf = Self {};
+        let slf = Self {
+            script,
+            crash_context,
+            error_sink,
+        };
         Ok(slf)
     }
 
@@ -563,6 +1073,84 @@ impl PythonInterpreter {
         }
     }
 
+    /// Like [Self::run], but instead of delegating to `Py_RunMain()` (which only yields an exit
+    /// code), replicates its dispatch under the GIL so you can inspect an uncaught exception
+    /// instead of just watching the process exit.
+    ///
+    /// Dispatches on the [PythonScript] the interpreter was built with ([PythonScript::REPL] is
+    /// run via the `code` module's `interact()`), then inspects the pending exception:
+    ///
+    /// - no exception: [PythonRunResult::Ok]
+    /// - [SystemExit]: [PythonRunResult::Exit], see [PythonRunResult::Exit] for how `code` is
+    ///   derived
+    /// - anything else: formatted as a traceback (via the `traceback` module) into
+    ///   [PythonRunResult::Err]
+    ///
+    /// Either way, the interpreter is finalized before returning, same as [Self::run].
+    ///
+    /// On [PythonRunResult::Err], also hands a [PythonCrashReport] to the [PythonErrorSink]
+    /// registered via [PythonInterpreterBuilder::with_error_sink] (a [FileErrorSink] by default).
+    ///
+    /// [SystemExit]: https://docs.python.org/3/library/exceptions.html#SystemExit
+    pub fn run_and_report(self) -> PythonRunResult {
+        let result = Python::with_gil(|py| -> PyResult<()> {
+            match &self.script {
+                PythonScript::File(path) => {
+                    let runpy = py.import("runpy")?;
+                    runpy.call_method1("run_path", (path.as_ref(), py.None(), "__main__"))?;
+                }
+                PythonScript::Module(module) => {
+                    let runpy = py.import("runpy")?;
+                    runpy.call_method1(
+                        "run_module",
+                        (module.as_ref(), py.None(), "__main__", true),
+                    )?;
+                }
+                PythonScript::Code(code) => {
+                    let code = CString::new(code.as_bytes())
+                        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+                    py.run(&code, None, None)?;
+                }
+                PythonScript::REPL => {
+                    py.import("code")?.call_method0("interact")?;
+                }
+            }
+            Ok(())
+        });
+
+        match result {
+            Ok(()) => PythonRunResult::Ok,
+            Err(err) => Python::with_gil(|py| {
+                if err.is_instance_of::<pyo3::exceptions::PySystemExit>(py) {
+                    let code = match err.value(py).getattr("code") {
+                        Ok(code) if !code.is_none() => code.extract::<i32>().unwrap_or(1),
+                        _ => 0,
+                    };
+                    PythonRunResult::Exit(code)
+                } else {
+                    let traceback = Self::format_traceback(py, &err)
+                        .unwrap_or_else(|_| err.to_string());
+                    self.error_sink.report(&PythonCrashReport {
+                        traceback: traceback.clone(),
+                        context: self.crash_context.clone(),
+                    });
+                    PythonRunResult::Err(traceback)
+                }
+            }),
+        }
+    }
+
+    /// Format `err` the way the real interpreter would on an uncaught exception, via Python's
+    /// `traceback` module (so it includes the traceback, not just the exception repr).
+    fn format_traceback(py: Python<'_>, err: &PyErr) -> PyResult<String> {
+        let formatted = py.import("traceback")?.call_method1(
+            "format_exception",
+            (err.get_type(py), err.value(py), err.traceback(py)),
+        )?;
+        let lines: Vec<String> = formatted.extract()?;
+        Ok(lines.concat())
+    }
+
     /// Proxy for [pyo3::Python::with_gil()].
     ///
     /// This function is just a wrapper around [pyo3::Python::with_gil()].
@@ -578,6 +1166,89 @@ impl PythonInterpreter {
     {
         Python::with_gil(f)
     }
+
+    /// Create a new, isolated [SubInterpreter] (`Py_NewInterpreter()`), with its own `__main__`
+    /// module and `sys.modules` import table.
+    ///
+    /// Useful for a standalone server-like app that wants to run independent scripts without
+    /// their global state (imported modules, `sys.path` mutations, etc.) leaking into each
+    /// other, without paying for a whole separate process.
+    ///
+    /// See [SubInterpreter] for the limitations of this approach.
+    pub fn new_sub_interpreter(&self) -> NewInterpreterResult<SubInterpreter> {
+        self.with_gil(|_py| {
+            // Py_NewInterpreter() makes the new thread state current; remember the one it
+            // replaces so we can restore it below, leaving `self`'s main interpreter current
+            // again once this function returns (as callers of `self.with_gil` expect).
+            let main_tstate = unsafe { pyffi::PyThreadState_Get() };
+            let sub_tstate = unsafe { pyffi::Py_NewInterpreter() };
+            if sub_tstate.is_null() {
+                return Err(NewInterpreterError::Simple(
+                    "Py_NewInterpreter() failed to create a sub-interpreter",
+                ));
+            }
+            unsafe { pyffi::PyThreadState_Swap(main_tstate) };
+            Ok(SubInterpreter { tstate: sub_tstate })
+        })
+    }
+}
+
+/// An isolated Python sub-interpreter, created via [PythonInterpreter::new_sub_interpreter].
+///
+/// Gives a standalone app a way to run independent scripts with isolated module namespaces
+/// (their own `__main__` and `sys.modules`) in the same process as the main interpreter, instead
+/// of spawning a whole separate process per task.
+///
+/// There is no public way to construct a [SubInterpreter] wrapping the main interpreter's thread
+/// state, so [Self::drop] can never accidentally end the main interpreter.
+///
+/// # Limitations
+///
+/// - All sub-interpreters (and the main interpreter) still share a single process-wide GIL:
+///   running code in one blocks every other interpreter in the process, the same as today.
+/// - C extension modules that don't support [multi-phase initialization] may behave incorrectly,
+///   or fail outright, when imported into more than one interpreter in the same process.
+///
+/// [multi-phase initialization]: https://docs.python.org/3/c-api/module.html#multi-phase-initialization
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct SubInterpreter {
+    tstate: *mut pyffi::PyThreadState,
+}
+
+// SAFETY: `tstate` is only ever dereferenced by the C API itself (`PyThreadState_Swap`,
+// `Py_EndInterpreter`), always while holding the GIL; we never read/write through it ourselves.
+unsafe impl Send for SubInterpreter {}
+
+impl SubInterpreter {
+    /// Run `f` with this sub-interpreter's thread state made current, i.e. Python code inside
+    /// `f` sees this sub-interpreter's `__main__`/`sys.modules`, not the main interpreter's (or
+    /// any other sub-interpreter's).
+    ///
+    /// The GIL is held for the duration of `f`, same as [Python::with_gil].
+    pub fn with_gil<F, R>(&self, f: F) -> R
+    where
+        F: for<'py> FnOnce(Python<'py>) -> R,
+    {
+        Python::with_gil(|_py| {
+            let previous = unsafe { pyffi::PyThreadState_Swap(self.tstate) };
+            let result = Python::with_gil(f);
+            unsafe { pyffi::PyThreadState_Swap(previous) };
+            result
+        })
+    }
+}
+
+/// Destroy the sub-interpreter (`Py_EndInterpreter()`).
+impl Drop for SubInterpreter {
+    fn drop(&mut self) {
+        Python::with_gil(|_py| unsafe {
+            // `Py_EndInterpreter()` requires its argument to be the current thread state.
+            let previous = pyffi::PyThreadState_Swap(self.tstate);
+            pyffi::Py_EndInterpreter(self.tstate);
+            pyffi::PyThreadState_Swap(previous);
+        });
+    }
 }
 
 /// Finalize the python interpreter
@@ -600,6 +1271,290 @@ impl Drop for PythonInterpreter {
     }
 }
 
+/// Resolve a [PythonInterpreterEnv::Standalone] root bundled as `resource_name` inside a macOS
+/// `.app` bundle's `Contents/Resources` directory, i.e. sibling to the running executable's
+/// `Contents/MacOS`.
+///
+/// You usually don't need to call this directly, see [bootstrap].
+#[cfg(target_os = "macos")]
+pub fn macos_bundle_resource_dir(resource_name: &str) -> NewInterpreterResult<PathBuf> {
+    let exe = current_exe().map_err(|e| {
+        NewInterpreterError::Dynamic(format!("failed to get the current executable path: {e}"))
+    })?;
+
+    // `<bundle>.app/Contents/MacOS/<exe>` -> `<bundle>.app/Contents/Resources/<resource_name>`
+    let contents_dir = exe
+        .parent() // Contents/MacOS
+        .and_then(Path::parent) // Contents
+        .ok_or_else(|| {
+            NewInterpreterError::Dynamic(format!(
+                "`{}` doesn't look like it's inside a macOS `.app` bundle \
+                 (expected the layout `Contents/MacOS/<exe>`)",
+                exe.display()
+            ))
+        })?;
+    Ok(contents_dir.join("Resources").join(resource_name))
+}
+
+// TODO: a "portable" initialization mode built against Python's stable ABI (`Py_LIMITED_API`),
+// so a single shipped binary can run against a range of interpreter versions without a
+// version-pinned `python3X.dll`/`libpython`. This needs `pyo3`'s `abi3` feature enabled
+// workspace-wide (a compile-time choice, not something [bootstrap] can switch at runtime), so
+// it's left for whoever wires up that Cargo feature; [bootstrap] below only automates *which*
+// interpreter [PythonInterpreterEnv] to point at, not the ABI it's built against.
+
+/// Automatically pick a [PythonInterpreterEnv] for the current process, instead of hand-rolling
+/// the per-platform `cfg` ladder:
+///
+/// 1. If the `VIRTUAL_ENV` env var is set (e.g. you activated a venv before `tauri dev`), use
+///    [PythonInterpreterEnv::Venv].
+/// 2. Otherwise, look for a bundled [python-build-standalone] distribution named `resource_name`:
+///    - macOS: `<bundle>.app/Contents/Resources/<resource_name>`, see
+///      [macos_bundle_resource_dir].
+///    - Windows/Linux: `<resource_name>` next to [current_exe].
+///
+///    If found, use [PythonInterpreterEnv::Standalone].
+///
+/// Returns a typed [NewInterpreterError] (instead of panicking) if neither is available, so app
+/// authors can decide how to surface it.
+///
+/// [python-build-standalone]: https://github.com/astral-sh/python-build-standalone
+pub fn bootstrap(resource_name: &str) -> NewInterpreterResult<PythonInterpreterEnv<'static>> {
+    if let Ok(venv_dir) = var("VIRTUAL_ENV") {
+        return Ok(PythonInterpreterEnv::Venv(PathBuf::from(venv_dir).into()));
+    }
+
+    #[cfg(target_os = "macos")]
+    let resource_dir = macos_bundle_resource_dir(resource_name)?;
+    #[cfg(not(target_os = "macos"))]
+    let resource_dir = {
+        let exe = current_exe().map_err(|e| {
+            NewInterpreterError::Dynamic(format!(
+                "failed to get the current executable path: {e}"
+            ))
+        })?;
+        let exe_dir = exe.parent().ok_or_else(|| {
+            NewInterpreterError::Dynamic(format!("`{}` has no parent directory", exe.display()))
+        })?;
+        exe_dir.join(resource_name)
+    };
+
+    if !resource_dir.is_dir() {
+        return Err(NewInterpreterError::Dynamic(format!(
+            "no embedded Python distribution found at `{}`; set the `VIRTUAL_ENV` env var for \
+             development, or bundle a `python-build-standalone` distribution there for production",
+            resource_dir.display()
+        )));
+    }
+    Ok(PythonInterpreterEnv::Standalone(resource_dir.into()))
+}
+
+/// Explicit override for [detect]: set to the root of a `python-build-standalone`-style
+/// distribution to skip every automatic probe below.
+pub const PYTAURI_PYTHON_ENV_VAR: &str = "PYTAURI_PYTHON_ENV";
+
+/// Resolve a [pixi] environment's root, if one exists at `<current dir>/.pixi/envs/<name>`,
+/// where `<name>` is the `PIXI_ENVIRONMENT_NAME` env var (set by `pixi run`/`pixi shell`), or
+/// `"default"` if unset.
+///
+/// You usually don't need to call this directly, see [detect].
+///
+/// [pixi]: https://pixi.sh
+pub fn pixi_env_dir() -> Option<PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    let name = var("PIXI_ENVIRONMENT_NAME").unwrap_or_else(|_| "default".to_owned());
+    let dir = cwd.join(".pixi").join("envs").join(name);
+    dir.is_dir().then_some(dir)
+}
+
+/// Resolve a conda environment's root from the `CONDA_PREFIX` env var, if its `conda-meta/`
+/// marker directory exists there.
+///
+/// You usually don't need to call this directly, see [detect].
+pub fn conda_env_dir() -> Option<PathBuf> {
+    let prefix = PathBuf::from(var("CONDA_PREFIX").ok()?);
+    prefix.join("conda-meta").is_dir().then_some(prefix)
+}
+
+/// Read the version pinned by the nearest `.python-version` file, searching upward from the
+/// current directory the same way `pyenv` itself resolves a local version.
+fn pyenv_version_file() -> Option<String> {
+    let mut dir = std::env::current_dir().ok()?;
+    loop {
+        let candidate = dir.join(".python-version");
+        if candidate.is_file() {
+            let contents = std::fs::read_to_string(candidate).ok()?;
+            return contents.lines().next().map(|line| line.trim().to_owned());
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+/// Resolve a [pyenv] version's root: `$(PYENV_ROOT or ~/.pyenv)/versions/<version>`, where
+/// `<version>` comes from the nearest `.python-version` file (we don't shell out to the `pyenv`
+/// binary, so this doesn't honor `PYENV_VERSION` or a version set via `pyenv global`/`pyenv shell`).
+///
+/// You usually don't need to call this directly, see [detect].
+///
+/// [pyenv]: https://github.com/pyenv/pyenv
+pub fn pyenv_version_dir() -> Option<PathBuf> {
+    let version = pyenv_version_file()?;
+    let root = var("PYENV_ROOT")
+        .map(PathBuf::from)
+        .or_else(|_| var("HOME").map(|home| PathBuf::from(home).join(".pyenv")))
+        .ok()?;
+    let dir = root.join("versions").join(version);
+    dir.is_dir().then_some(dir)
+}
+
+/// Automatically pick a [PythonInterpreterEnv] for the current process. Collapses the
+/// hand-rolled, per-tool bootstrap logic (reading `VIRTUAL_ENV`, joining `.pixi/envs/default`,
+/// canonicalizing `PYTHONHOME`, ...) that every `main.rs` in this ecosystem tends to reinvent
+/// into one probe, checked in this order, first match wins:
+///
+/// 1. [PYTAURI_PYTHON_ENV_VAR] env var: an explicit override, used as [PythonInterpreterEnv::Standalone].
+/// 2. `VIRTUAL_ENV` env var: [PythonInterpreterEnv::Venv].
+/// 3. [pixi_env_dir]: [PythonInterpreterEnv::Standalone].
+/// 4. [conda_env_dir]: [PythonInterpreterEnv::Standalone].
+/// 5. [pyenv_version_dir]: [PythonInterpreterEnv::Standalone].
+/// 6. Everything [bootstrap] does (a bundled [python-build-standalone] distribution named
+///    `resource_name`): [PythonInterpreterEnv::Standalone].
+///
+/// Returns a typed [NewInterpreterError] (instead of panicking) if none of the above match, so
+/// app authors can decide how to surface it.
+///
+/// [python-build-standalone]: https://github.com/astral-sh/python-build-standalone
+pub fn detect(resource_name: &str) -> NewInterpreterResult<PythonInterpreterEnv<'static>> {
+    if let Ok(dir) = var(PYTAURI_PYTHON_ENV_VAR) {
+        return Ok(PythonInterpreterEnv::Standalone(PathBuf::from(dir).into()));
+    }
+
+    if let Ok(venv_dir) = var("VIRTUAL_ENV") {
+        return Ok(PythonInterpreterEnv::Venv(PathBuf::from(venv_dir).into()));
+    }
+
+    if let Some(dir) = pixi_env_dir() {
+        return Ok(PythonInterpreterEnv::Standalone(dir.into()));
+    }
+
+    if let Some(dir) = conda_env_dir() {
+        return Ok(PythonInterpreterEnv::Standalone(dir.into()));
+    }
+
+    if let Some(dir) = pyenv_version_dir() {
+        return Ok(PythonInterpreterEnv::Standalone(dir.into()));
+    }
+
+    bootstrap(resource_name)
+}
+
+/// A Python installation's `sys.prefix`/`sys.base_prefix`/`sys.version_info`, as reported by the
+/// interpreter itself rather than guessed from its on-disk layout.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct PythonVersionInfo {
+    pub prefix: PathBuf,
+    pub base_prefix: PathBuf,
+    /// `"<major>.<minor>"`, e.g. `"3.11"`.
+    pub version: String,
+    /// `32` or `64`, derived from `sys.maxsize`.
+    pub pointer_width: u8,
+}
+
+impl PythonVersionInfo {
+    /// Make sure [Self::pointer_width] matches the pointer width we were compiled for.
+    ///
+    /// Like [PythonInterpreterEnv::executable_path]'s `windows`/`unix` branching, "the pointer
+    /// width we were compiled for" already means *the build target's*, not the host's:
+    /// [usize::BITS] is resolved for whatever target this crate itself is being compiled for, so
+    /// this check is cross-compilation-aware for free.
+    ///
+    /// Catches e.g. accidentally bundling a 32-bit `python-build-standalone` distribution next
+    /// to a 64-bit build, which would otherwise surface (if at all) as a bewildering crash deep
+    /// inside CPython's own initialization.
+    pub fn validate_pointer_width(&self) -> NewInterpreterResult<()> {
+        let target_pointer_width = usize::BITS as u8;
+        if self.pointer_width != target_pointer_width {
+            return Err(NewInterpreterError::Dynamic(format!(
+                "embedded python at `{}` is {}-bit, but this binary was built for a {}-bit \
+                 target",
+                self.prefix.display(),
+                self.pointer_width,
+                target_pointer_width,
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Invoke `python_executable -c "..."` to read [PythonVersionInfo] straight out of `sys`, the
+/// same thing every hand-rolled `main.rs` bootstrap in this ecosystem used to do for itself.
+pub fn probe_python_info(python_executable: &Path) -> NewInterpreterResult<PythonVersionInfo> {
+    let output = StdCommand::new(python_executable)
+        .args([
+            "-c",
+            "import sys; print(sys.prefix); print(sys.base_prefix); \
+             print(f'{sys.version_info.major}.{sys.version_info.minor}'); \
+             print(64 if sys.maxsize > 2**32 else 32)",
+        ])
+        .output()
+        .map_err(|e| {
+            NewInterpreterError::Dynamic(format!(
+                "failed to run `{}`: {e}",
+                python_executable.display()
+            ))
+        })?;
+
+    if !output.status.success() {
+        return Err(NewInterpreterError::Dynamic(format!(
+            "`{}` exited with {}: {}",
+            python_executable.display(),
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let mut next_line = || {
+        lines.next().ok_or_else(|| {
+            NewInterpreterError::Dynamic(format!(
+                "`{}` produced unexpected output: {stdout:?}",
+                python_executable.display()
+            ))
+        })
+    };
+    let prefix = PathBuf::from(next_line()?);
+    let base_prefix = PathBuf::from(next_line()?);
+    let version = next_line()?.to_owned();
+    let pointer_width = next_line()?.parse().map_err(|_| {
+        NewInterpreterError::Dynamic(format!(
+            "`{}` produced unexpected output: {stdout:?}",
+            python_executable.display()
+        ))
+    })?;
+
+    Ok(PythonVersionInfo {
+        prefix,
+        base_prefix,
+        version,
+        pointer_width,
+    })
+}
+
+/// Like [detect], but also returns [PythonVersionInfo] for the interpreter it picked, by
+/// invoking the executable that [PythonInterpreterEnv]'s variant implies (the same path
+/// [PythonInterpreterEnv::executable_path] would compute).
+pub fn detect_with_info(
+    resource_name: &str,
+) -> NewInterpreterResult<(PythonInterpreterEnv<'static>, PythonVersionInfo)> {
+    let env = detect(resource_name)?;
+    let info = probe_python_info(&env.executable_path())?;
+    Ok((env, info))
+}
+
 /// This is a re-export of crate [::dunce] to help you remove the UNC prefix `\\?\` for [PythonInterpreterEnv::Standalone].
 ///
 /// Most Python ecosystems do not support Windows [Universal Naming Convention (UNC) paths] (e.g., `\\?\E:\xxx`).
@@ -616,6 +1571,10 @@ impl Drop for PythonInterpreter {
 ///
 /// [Universal Naming Convention (UNC) paths]: https://learn.microsoft.com/dotnet/standard/io/file-path-formats#unc-paths
 ///
+/// UNC paths only exist on Windows, so [dunce::simplified] is already a no-op everywhere else;
+/// it's safe to call it unconditionally, including when cross-compiling for a non-Windows
+/// target.
+///
 /// # Example
 ///
 /**
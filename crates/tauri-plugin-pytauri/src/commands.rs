@@ -6,9 +6,17 @@ use tauri::ipc;
 
 type IpcInvoke = ipc::Invoke<PyTauriRuntime>;
 
+#[cfg(not(feature = "unsafe-allow-subinterpreters"))]
 use crate::gil_runtime::task_with_gil;
+#[cfg(feature = "unsafe-allow-subinterpreters")]
+use crate::gil_runtime::GIL_RUNTIME;
+#[cfg(feature = "unsafe-allow-subinterpreters")]
+use crate::subinterpreter::SubInterpreterPool;
 use crate::PyInvokeHandlerExt as _;
+#[cfg(feature = "unsafe-allow-subinterpreters")]
+use tauri::Manager as _;
 
+#[cfg(not(feature = "unsafe-allow-subinterpreters"))]
 fn pyfunc(invoke: IpcInvoke) {
     task_with_gil(move |py| {
         let py_invoke_handler = invoke
@@ -39,6 +47,58 @@ fn pyfunc(invoke: IpcInvoke) {
     });
 }
 
+/// Same contract as the default `pyfunc` above, except `py_invoke_handler` runs with the
+/// invoking webview's own subinterpreter current, instead of the main interpreter, so that
+/// module-level Python state doesn't leak between webviews.
+#[cfg(feature = "unsafe-allow-subinterpreters")]
+fn pyfunc(invoke: IpcInvoke) {
+    // `key` must be read out before `invoke` is moved into the spawned future below.
+    let key = invoke.message.webview_ref().label().to_owned();
+
+    let future = async move {
+        let pool = invoke
+            .message
+            .webview_ref()
+            .try_state::<SubInterpreterPool>()
+            // it's ok to `unwrap` here, because the plugin is already initialized
+            .unwrap();
+
+        let result = pool.with_gil(&key, |py| {
+            let py_invoke_handler = invoke
+                .message
+                .webview_ref()
+                .try_py_invoke_handler()
+                // it's ok to `unwrap` here, because the plugin is already initialized
+                .unwrap()
+                .bind(py)
+                .clone();
+
+            let invoke = match Invoke::new(py, invoke) {
+                Some(invoke) => invoke,
+                None => return, // the ipc has already been handled and rejected
+            };
+
+            // NOTE: We require that the implementation of `py_invoke_handler`
+            // does not block for a long time, so this call will not block
+            // the tokio runtime.
+            if let Err(e) = py_invoke_handler.call1((invoke,)) {
+                let new_err = PyRuntimeError::new_err("`py_invoke_handler` raised an exception");
+                new_err.set_cause(py, Some(e));
+                new_err.write_unraisable(py, Some(&py_invoke_handler));
+                // TODO: use `log` instead of `panic!`,
+                // it's because the joinhandle will never be awaited
+                panic!("`py_invoke_handler` shouldn't raise exception");
+            }
+        });
+
+        if let Err(e) = result {
+            Python::with_gil(|py| e.write_unraisable(py, None));
+            panic!("failed to create/acquire the webview's sub-interpreter");
+        }
+    };
+    GIL_RUNTIME.spawn(future);
+}
+
 pub(crate) fn invoke_handler(invoke: IpcInvoke) -> bool {
     match invoke.message.command() {
         "pyfunc" => {
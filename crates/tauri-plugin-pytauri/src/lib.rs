@@ -3,6 +3,8 @@
 
 mod commands;
 mod gil_runtime;
+#[cfg(feature = "unsafe-allow-subinterpreters")]
+mod subinterpreter;
 
 use std::error::Error;
 use std::fmt::Display;
@@ -51,6 +53,13 @@ impl PyInvokeHandler {
 /// - `py_invoke_handler` will be called in a tokio runtime, so it must not block for a long time.
 ///     - `tokio runtime` means it is running on an external thread.
 /// - `py_invoke_handler` must not raise exceptions, otherwise it will result in logical undefined behavior.
+///
+/// # `unsafe-allow-subinterpreters`
+///
+/// If that Cargo feature is enabled, each call of `py_invoke_handler` instead runs inside the
+/// invoking webview's own CPython subinterpreter (see `crate::subinterpreter`), so one frontend context
+/// can't clobber another's module-level globals. See that module's safety docs before enabling
+/// it.
 pub fn init(py_invoke_handler: PyInvokeHandlerType) -> TauriPlugin<PyTauriRuntime> {
     Builder::<PyTauriRuntime>::new(PLUGIN_NAME)
         .invoke_handler(invoke_handler)
@@ -61,6 +70,12 @@ pub fn init(py_invoke_handler: PyInvokeHandlerType) -> TauriPlugin<PyTauriRuntim
                     "`PyInvokeHandler` is private, so it is impossible for other crates to manage it"
                 )
             }
+            #[cfg(feature = "unsafe-allow-subinterpreters")]
+            if !app_handle.manage(crate::subinterpreter::SubInterpreterPool::new()) {
+                unreachable!(
+                    "`SubInterpreterPool` is private, so it is impossible for other crates to manage it"
+                )
+            }
             Ok(())
         })
         .build()
@@ -0,0 +1,121 @@
+//! Opt-in per-webview CPython subinterpreter isolation for [crate::commands::pyfunc].
+//!
+//! Each subinterpreter gets its own GIL (`PyInterpreterConfig_OWN_GIL`), not just its own
+//! `__main__`/`sys.modules`. Calls are still serialized: [SubInterpreterPool::with_gil] holds the
+//! pool's lock, inside the main interpreter's GIL, for as long as `f` runs, so one webview
+//! blocked on Python stalls IPC for every other webview.
+//!
+//! # Safety
+//!
+//! Only compiled in behind the `unsafe-allow-subinterpreters` Cargo feature, off by default.
+//! Once more than one interpreter is alive in the process, a `Py`/`PyObject` created under one
+//! subinterpreter must never be touched while a *different* subinterpreter's thread state is
+//! current — so storing Python objects in Rust statics (or any other state shared across invoke
+//! contexts) is undefined behavior. Only enable this feature if neither pytauri nor your own app
+//! code caches Python objects in global/static state. Extension modules reachable from a
+//! subinterpreter must also support multi-phase init (`check_multi_interp_extensions = 1` below
+//! enforces this at the C API level); the `ext_mod` that `pytauri` wires up already declares
+//! `gil_used = false`, which implies it.
+//!
+//! # NOTE
+//!
+//! This duplicates (rather than reuses) the subinterpreter primitive in
+//! `pytauri::standalone::SubInterpreter`: `pytauri` depends on this crate (to wire up
+//! [crate::init] from its `Builder`), so this crate can't depend back on `pytauri` without a
+//! cycle.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::ffi as pyffi;
+use pyo3::prelude::*;
+
+/// A single webview's subinterpreter thread state, ended on [Drop].
+struct SubInterpreterHandle {
+    tstate: *mut pyffi::PyThreadState,
+}
+
+// SAFETY: `tstate` is only ever passed to `PyThreadState_Swap`/`Py_EndInterpreter`, always while
+// holding the GIL; we never dereference it ourselves.
+unsafe impl Send for SubInterpreterHandle {}
+
+impl SubInterpreterHandle {
+    fn new() -> PyResult<Self> {
+        Python::with_gil(|_py| {
+            // `check_multi_interp_extensions = 1` requires extension modules reachable from this
+            // subinterpreter to support multi-phase init (the existing `ext_mod` already declares
+            // `gil_used = false`, which implies that). `gil = PyInterpreterConfig_OWN_GIL` gives
+            // this subinterpreter its own GIL instead of sharing the main interpreter's.
+            let mut config: pyffi::PyInterpreterConfig = unsafe { std::mem::zeroed() };
+            config.check_multi_interp_extensions = 1;
+            config.gil = pyffi::PyInterpreterConfig_OWN_GIL;
+
+            // `Py_NewInterpreterFromConfig()` makes the new thread state current; remember the
+            // one it replaces so we can restore it below.
+            let main_tstate = unsafe { pyffi::PyThreadState_Get() };
+            let mut sub_tstate: *mut pyffi::PyThreadState = std::ptr::null_mut();
+            let status =
+                unsafe { pyffi::Py_NewInterpreterFromConfig(&mut sub_tstate, &config) };
+            if unsafe { pyffi::PyStatus_Exception(status) } != 0 {
+                return Err(PyRuntimeError::new_err(
+                    "Py_NewInterpreterFromConfig() failed to create a sub-interpreter",
+                ));
+            }
+            unsafe { pyffi::PyThreadState_Swap(main_tstate) };
+            Ok(Self { tstate: sub_tstate })
+        })
+    }
+
+    fn with_gil<F, R>(&self, f: F) -> R
+    where
+        F: for<'py> FnOnce(Python<'py>) -> R,
+    {
+        Python::with_gil(|_py| {
+            let previous = unsafe { pyffi::PyThreadState_Swap(self.tstate) };
+            let result = Python::with_gil(f);
+            unsafe { pyffi::PyThreadState_Swap(previous) };
+            result
+        })
+    }
+}
+
+impl Drop for SubInterpreterHandle {
+    fn drop(&mut self) {
+        Python::with_gil(|_py| unsafe {
+            // `Py_EndInterpreter()` requires its argument to be the current thread state.
+            let previous = pyffi::PyThreadState_Swap(self.tstate);
+            pyffi::Py_EndInterpreter(self.tstate);
+            pyffi::PyThreadState_Swap(previous);
+        });
+    }
+}
+
+/// Maps an invoke context key (the originating webview's label, see [crate::commands::pyfunc])
+/// to its own subinterpreter, creating one the first time a key is seen.
+///
+/// Managed as tauri app state by [crate::init], get it via
+/// [Manager::state](tauri::Manager::state).
+#[derive(Default)]
+pub(crate) struct SubInterpreterPool {
+    handles: Mutex<HashMap<String, SubInterpreterHandle>>,
+}
+
+impl SubInterpreterPool {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `f` with the subinterpreter registered for `key` made current, i.e. Python code
+    /// inside `f` sees that subinterpreter's own `__main__`/`sys.modules`.
+    pub(crate) fn with_gil<F, R>(&self, key: &str, f: F) -> PyResult<R>
+    where
+        F: for<'py> FnOnce(Python<'py>) -> R,
+    {
+        let mut handles = self.handles.lock().unwrap_or_else(|e| e.into_inner());
+        if !handles.contains_key(key) {
+            handles.insert(key.to_owned(), SubInterpreterHandle::new()?);
+        }
+        Ok(handles[key].with_gil(f))
+    }
+}
@@ -0,0 +1,98 @@
+use std::{env::var, path::PathBuf, sync::LazyLock};
+
+use pyo3::{
+    prelude::*,
+    types::{PyCFunction, PyDict},
+    wrap_pymodule,
+};
+use pytauri::{
+    ext_mod,
+    standalone::{PythonInterpreter, PythonInterpreterBuilder, PythonInterpreterEnv, PythonScript},
+};
+use tauri::webview::WebviewWindowBuilder;
+
+use pytauri_test::test::ext_mod as test_ext_mod;
+
+static PYI: LazyLock<PythonInterpreter> = LazyLock::new(|| {
+    let virtual_env = var("VIRTUAL_ENV").unwrap();
+    let py_env = PythonInterpreterEnv::Venv(PathBuf::from(virtual_env).into());
+    let py_script = PythonScript::REPL;
+    let builder =
+        PythonInterpreterBuilder::new(py_env, py_script, |py| wrap_pymodule!(test_ext_mod)(py));
+    builder.build().unwrap()
+});
+
+/// Per-item handlers (`MenuItem(handler=...)`/`set_handler`) are meant to fire no matter which
+/// of the three menu-event entry points the app also registers — `AppHandle.on_menu_event`,
+/// `WebviewWindow.on_menu_event`, or `Manager.menu_events` — see `menu::MenuItemHandlers`'s doc
+/// comment. Since Tauri's own `on_menu_event` registration is last-call-wins, an app using more
+/// than one of these at once used to silently lose the earlier registration's per-item
+/// dispatch; all three now route through `MenuItemHandlers::dispatch` first, so they can be
+/// registered together without one clobbering another.
+///
+/// This registers all three alongside a per-item handler and asserts the whole sequence
+/// succeeds. It does not simulate an actual click: [tauri::test::MockRuntime] has no public hook
+/// to fire a real `tauri::menu::MenuEvent`, so the per-item callback actually running through
+/// each entry point isn't asserted here directly — that's enforced by `MenuItemHandlers::dispatch`
+/// being the one function all three entry points now call into.
+#[test]
+fn test_menu_handlers_registration() -> PyResult<()> {
+    PYI.with_gil(|py| {
+        let test_mod = py.import("pytauri_test")?;
+        let fixture = test_mod.getattr("app_handle_fixture")?;
+        let context_manager = fixture.call0()?;
+
+        let py_app_handle = context_manager
+            .call_method0("__enter__")?
+            .downcast_into::<ext_mod::AppHandle>()?;
+
+        let result = (|| -> PyResult<()> {
+            {
+                let app_handle = py_app_handle.get().0.inner_ref();
+                WebviewWindowBuilder::new(&*app_handle, "main", Default::default())
+                    .build()
+                    .expect("building the `main` window should not fail");
+            }
+
+            let manager_cls = py.get_type::<ext_mod::Manager>();
+
+            let py_webview_window = manager_cls
+                .call_method1("get_webview_window", (&py_app_handle, "main"))?;
+            let py_webview_window = py_webview_window
+                .downcast::<ext_mod::webview::WebviewWindow>()
+                .expect("`main` window was just built above, so this should be `Some`");
+
+            let noop = PyCFunction::new_closure(py, Some(c"noop"), None, |_args, _kwargs| {
+                PyResult::<()>::Ok(())
+            })?;
+
+            py_app_handle.call_method1("on_menu_event", (&noop,))?;
+            py_webview_window.call_method1("on_menu_event", (&noop,))?;
+            manager_cls.call_method1("menu_events", (&py_app_handle, 0usize))?;
+
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("manager", &py_app_handle)?;
+            kwargs.set_item("text", "item")?;
+            kwargs.set_item("enabled", true)?;
+            kwargs.set_item("handler", &noop)?;
+            py.get_type::<ext_mod::menu::MenuItem>()
+                .call((), Some(&kwargs))?;
+
+            Ok(())
+        })();
+
+        let py_none = py.None();
+        match &result {
+            Ok(()) => {
+                context_manager.call_method1("__exit__", (&py_none, &py_none, &py_none))?;
+            }
+            Err(e) => {
+                context_manager.call_method1(
+                    "__exit__",
+                    (e.get_type(py), e.value(py), e.traceback(py)),
+                )?;
+            }
+        }
+        result
+    })
+}